@@ -0,0 +1,74 @@
+/**
+ * benchmark.js — `?benchmark`: one timed pass through the shape library.
+ *
+ * The sequencer hands out shapes in order.  For each one it records how long
+ * the build took (NCA + OT, from the `transition_done` event), how long until
+ * the atoms settled (request → morph complete), and the mean frame time over
+ * the whole visit.  Once a shape has settled and dwelt for `dwellMs` the next
+ * one is due; after the last, `report()` holds one row per shape for
+ * console.table.
+ *
+ * Runs are seeded (see random.js) so two builds can be compared shape by shape.
+ */
+
+import { hasQueryParam } from './query.js';
+
+export const BENCHMARK = hasQueryParam('benchmark');
+
+/**
+ * @param {string[]} shapes    visited in order
+ * @param {number}   dwellMs   time to stay on each shape once it has settled
+ */
+export function createBenchmark(shapes, dwellMs) {
+    const rows = [];
+    let idx = -1;
+    let cur = null;
+
+    function close() {
+        if (!cur) return;
+        rows.push({
+            shape:       cur.shape,
+            'build ms':  cur.buildMs,
+            'settle ms': cur.settleMs,
+            'frame ms':  +(cur.frameMs / Math.max(cur.frames, 1)).toFixed(2),
+            fps:         Math.round(1000 * cur.frames / Math.max(cur.frameMs, 1)),
+        });
+    }
+
+    return {
+        /** Finish the current shape and return the next, or null when done. */
+        next() {
+            close();
+            idx++;
+            if (idx >= shapes.length) { cur = null; return null; }
+            cur = { shape: shapes[idx], start: performance.now(), frames: 0,
+                    frameMs: 0, dwell: 0, buildMs: null, settleMs: null };
+            return cur.shape;
+        },
+
+        /** Build time of the current shape, in ms. */
+        built(ms) {
+            if (cur) cur.buildMs = ms;
+        },
+
+        /**
+         * Account one frame.  Returns true when the next shape is due.
+         * @param {number}  ms       wall-clock frame time
+         * @param {boolean} settled  the current shape's morph has completed
+         */
+        frame(ms, settled) {
+            if (!cur) return false;
+            cur.frames++;
+            cur.frameMs += ms;
+            if (!settled) return false;
+            if (cur.settleMs === null) cur.settleMs = Math.round(performance.now() - cur.start);
+            cur.dwell += ms;
+            return cur.dwell >= dwellMs;
+        },
+
+        /** One row per finished shape. */
+        report() {
+            return rows;
+        },
+    };
+}
@@ -4,9 +4,32 @@
  * JS files import directly from here.
  * WGSL files receive these values via the Vite wgsl-constants plugin, which
  * replaces %%CONST_NAME%% placeholders at transform time.
+ *
+ * The atom count can be overridden per page load with `?n=<count>`
+ * (e.g. `?n=200000` on a laptop); everything else is fixed.
  */
 
-export const N         = 2_000_000; // atom count — sweet spot for solid 4K coverage at ~60fps
+import { queryParam } from './query.js';
+
+const N_MIN = 4_096;       // k-means needs at least K atoms; keep a margin
+const N_MAX = 2_000_000;   // k-means i32 sums: N · SCALE must stay < 2^31 (see kmeans_update.wgsl)
+
+/** Atom count from the `?n=` query parameter, clamped, else `fallback`. */
+function atomCount(fallback) {
+    const raw = queryParam('n');
+    if (raw === null) return fallback;
+
+    const n = Number(raw.replace(/[_,\s]/g, ''));
+    if (!Number.isInteger(n) || n <= 0) {
+        console.warn(`[tofu] ignoring invalid ?n=${raw} — using ${fallback}`);
+        return fallback;
+    }
+    const clamped = Math.min(Math.max(n, N_MIN), N_MAX);
+    if (clamped !== n) console.warn(`[tofu] ?n=${n} clamped to ${clamped}`);
+    return clamped;
+}
+
+export const N         = atomCount(2_000_000); // sweet spot for solid 4K coverage at ~60fps
 export const DENSITY_W = 3840;      // density grid width  (pixels)
 export const DENSITY_H = 2160;      // density grid height (pixels)
 export const K         = 512;       // k-means centroids
@@ -0,0 +1,36 @@
+/**
+ * easing.js — Easing curves on the CPU, mirroring ease() in physics.wgsl.
+ *
+ * Single responsibility: name the curves and evaluate them in JS for CPU-side
+ * ramps (e.g. the crossfade).  EASINGS is in selector order, so
+ * `EASINGS.indexOf(name)` is the value physics.wgsl's `easing` field expects;
+ * the two lists must change together.
+ */
+
+/** Curve names; index = EASE_* selector in physics.wgsl.  `spring` stays last. */
+export const EASINGS = [
+    'linear', 'easeInOut', 'easeOutCubic', 'easeOutBack', 'easeOutElastic',
+    'easeInOutQuad', 'easeInOutCubic', 'spring',
+];
+
+const CURVES = {
+    linear:         t => t,
+    easeInOut:      t => t * t * (3 - 2 * t),                      // smoothstep
+    easeOutCubic:   t => 1 + (t - 1) ** 3,
+    easeOutBack:    t => 1 + 2.70158 * (t - 1) ** 3 + 1.70158 * (t - 1) ** 2,
+    easeOutElastic: t => t <= 0 || t >= 1 ? t
+        : 2 ** (-10 * t) * Math.sin((10 * t - 0.75) * 2.0943951) + 1,
+    easeInOutQuad:  t => t < 0.5 ? 2 * t * t : 1 - 2 * (1 - t) ** 2,
+    easeInOutCubic: t => t < 0.5 ? 4 * t ** 3 : 1 - 4 * (1 - t) ** 3,
+};
+
+/**
+ * Evaluate curve `name` at `t` (clamped to [0, 1]).  `spring` has no closed
+ * form — the GPU integrates it — so it, like an unknown name, is linear here.
+ * @param {number} t
+ * @param {string} name  one of EASINGS
+ */
+export function ease(t, name) {
+    const c = Math.min(Math.max(t, 0), 1);
+    return (CURVES[name] ?? CURVES.linear)(c);
+}
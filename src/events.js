@@ -0,0 +1,26 @@
+/**
+ * events.js — Structured pipeline events for outside tools.
+ *
+ * Every record is dispatched on `window` as a `tofu` CustomEvent (the record
+ * is `event.detail`), so an embedding page or userscript can follow what the
+ * app is doing.  With `?log=json` in the URL each record is also printed to
+ * the console as one line of JSON; the usual console output is unchanged.
+ *
+ *   { "event": "transition_done", "t": 5120, "prompt": "spiral galaxy",
+ *     "shape": "galaxy2", "ms": 820, "points": 2000000 }
+ */
+
+import { queryParam } from './query.js';
+
+const JSON_LOGS = queryParam('log') === 'json';
+
+/**
+ * Emit one record.  `t` is milliseconds since page load.
+ * @param {string} event   snake_case event name
+ * @param {object} fields  extra JSON-serialisable fields
+ */
+export function emit(event, fields = {}) {
+    const record = { event, t: Math.round(performance.now()), ...fields };
+    globalThis.dispatchEvent?.(new CustomEvent('tofu', { detail: record }));
+    if (JSON_LOGS) console.log(JSON.stringify(record));
+}
@@ -11,6 +11,8 @@
  */
 
 import { N, DENSITY_W, DENSITY_H } from '../constants.js';
+import { random }                  from '../random.js';
+import { RENDER_PARAMS_BYTES }     from './render-params.js';
 export { N, DENSITY_W, DENSITY_H };
 
 // Derived sizes
@@ -33,7 +35,8 @@ export const DISPATCH = Math.ceil(N / 256);             // 391
  *   atomBufs   : GPUBuffer[2],   ping-pong atom state
  *   sourceBuf  : GPUBuffer,      OT source positions
  *   targetBuf  : GPUBuffer,      OT target positions
- *   simBuf     : GPUBuffer,      SimParams uniform (16 bytes)
+ *   simBuf     : GPUBuffer,      SimParams uniform (96 bytes)
+ *   renderBuf  : GPUBuffer,      RenderParams uniform (144 bytes)
  *   densityBuf : GPUBuffer,      atomic u32 density accumulator
  * }}
  */
@@ -49,7 +52,8 @@ export function allocateBuffers(device) {
         atomBufs:   [0, 1].map(i => buf(ATOM_BYTES,    S,     `atoms-${i}`)),
         sourceBuf:               buf(OT_BYTES,      S,     'ot-source'),
         targetBuf:               buf(OT_BYTES,      S,     'ot-target'),
-        simBuf:                  buf(16,             U,     'sim-params'),
+        simBuf:                  buf(96,             U,     'sim-params'),
+        renderBuf:               buf(RENDER_PARAMS_BYTES, U, 'render-params'),
         densityBuf:              buf(DENSITY_BYTES,  S,     'density'),
         velBuf:                  buf(VEL_BYTES,      S,     'velocity'),
         trailBuf:                buf(TRAIL_BYTES,    S,     'trail'),
@@ -67,8 +71,8 @@ export function allocateBuffers(device) {
 export function seedAtoms(device, atomBufs) {
     const seed = new Float32Array(N * 4);   // {pos.x, pos.y, vel.x, vel.y} × N
     for (let i = 0; i < N; i++) {
-        seed[i * 4    ] = (Math.random() * 2 - 1) * 0.85;  // pos.x
-        seed[i * 4 + 1] = (Math.random() * 2 - 1) * 0.85;  // pos.y
+        seed[i * 4    ] = (random() * 2 - 1) * 0.85;  // pos.x
+        seed[i * 4 + 1] = (random() * 2 - 1) * 0.85;  // pos.y
         // vel.x, vel.y remain 0
     }
     device.queue.writeBuffer(atomBufs[0], 0, seed);
@@ -14,19 +14,32 @@ export async function initDevice(canvas) {
         throw new Error('WebGPU not supported in this browser.');
     }
 
-    const adapter = await navigator.gpu.requestAdapter({
+    // Software fallback (e.g. SwiftShader) keeps headless / GPU-less machines running
+    let adapter = await navigator.gpu.requestAdapter({
         powerPreference: 'high-performance',
     });
+    if (!adapter) {
+        adapter = await navigator.gpu.requestAdapter({ forceFallbackAdapter: true });
+        if (adapter) console.warn('[gpu] No hardware adapter — using the fallback adapter.');
+    }
     if (!adapter) {
         throw new Error('No WebGPU adapter found (driver/browser issue).');
     }
 
-    const device = await adapter.requestDevice({ label: 'tofu-v2' });
+    let device;
+    try {
+        device = await adapter.requestDevice({ label: 'tofu-v2' });
+    } catch (e) {
+        throw new Error(`WebGPU device request failed: ${e.message}`);
+    }
     device.lost.then(info => {
         console.error('[gpu] Device lost:', info.reason, info.message);
     });
 
     const ctx    = canvas.getContext('webgpu');
+    if (!ctx) {
+        throw new Error('Could not create a WebGPU canvas context.');
+    }
     const format = navigator.gpu.getPreferredCanvasFormat();
     ctx.configure({ device, format, alphaMode: 'opaque' });
 
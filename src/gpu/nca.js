@@ -16,6 +16,7 @@
 import rdsCode from '../../wgsl/nca_step.wgsl?raw';
 import mlpCode from '../../wgsl/nca_step_mlp.wgsl?raw';
 import extCode from '../../wgsl/nca_extract.wgsl?raw';
+import { random } from '../random.js';
 
 export const NCA_W = 128;
 export const NCA_H = 128;
@@ -238,7 +239,7 @@ async function runRDS(device, nca, goalGrid) {
     // Seed: goalGrid + tiny noise
     const seed = new Float32Array(NCA_CELLS);
     for (let i = 0; i < NCA_CELLS; i++)
-        seed[i] = Math.max(0, Math.min(1, goalGrid[i] + (Math.random() - 0.5) * 0.08));
+        seed[i] = Math.max(0, Math.min(1, goalGrid[i] + (random() - 0.5) * 0.08));
     device.queue.writeBuffer(stateBufs[0], 0, seed);
 
     const enc = device.createCommandEncoder({ label: 'nca-rds-run' });
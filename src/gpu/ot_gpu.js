@@ -286,13 +286,14 @@ function sortByAngle(pos, n, cx, cy) {
 /**
  * Assign N source atoms to N target positions using GPU K-means + CPU centroid OT,
  * then run the freeze_filter shader on GPU to write the final per-atom targets
- * directly into targetBuf.
+ * directly into targetBuf.  With `targetBuf` null the filter is skipped and only
+ * the assignment is returned (for callers that combine several before writing).
  *
  * @param {GPUDevice}    device
  * @param {object}       ot          Handle from buildOTGpu()
  * @param {Float32Array} srcPos      N×2 current atom positions (used as OT source)
  * @param {Float32Array} tgtPos      N×2 freshly sampled target positions
- * @param {GPUBuffer}    targetBuf   Simulation target buffer — written in-place by shader
+ * @param {GPUBuffer|null} targetBuf Simulation target buffer — written in-place by shader
  */
 export async function assignTargetsGpu(device, ot, srcPos, tgtPos, targetBuf) {
     // Run k-means sequentially (they share GPU buffers)
@@ -330,6 +331,8 @@ export async function assignTargetsGpu(device, ot, srcPos, tgtPos, targetBuf) {
         result[i * 2 + 1] = tgtPos[j * 2 + 1];
     }
 
+    if (!targetBuf) return result;
+
     // ── GPU freeze filter ─────────────────────────────────────────────────────
     // Upload OT result and current-position reference to GPU, then dispatch
     // the shader which decides per-atom whether to freeze or move.
@@ -29,7 +29,8 @@ const decayCode   = applyConstants(_decayCode);
 const renderCode  = applyConstants(_renderCode);
 
 export async function buildPipelines(device, buffers, format) {
-    const { atomBufs, sourceBuf, targetBuf, simBuf, densityBuf, velBuf, trailBuf } = buffers;
+    const { atomBufs, sourceBuf, targetBuf, simBuf, renderBuf,
+            densityBuf, velBuf, trailBuf } = buffers;
 
     // ── Shader modules ──────────────────────────────────────────────────────
     const physicsMod = device.createShaderModule({ label: 'physics', code: physicsCode });
@@ -128,6 +129,7 @@ export async function buildPipelines(device, buffers, format) {
             { binding: 0, resource: buf(trailBuf)   },
             { binding: 1, resource: buf(velBuf)     },
             { binding: 2, resource: buf(densityBuf) },
+            { binding: 3, resource: buf(renderBuf)  },  // colour params
         ],
     });
 
@@ -142,16 +144,25 @@ export async function buildPipelines(device, buffers, format) {
  *   3. Decay compute pass  (density → persistent trail)
  *   4. Render pass         (fullscreen quad)
  *
+ * With `simulate` false only the render pass is encoded, redrawing the last
+ * simulated frame (used while paused).
+ *
  * @param {GPUCommandEncoder} enc
  * @param {object}            pipelines  — result of buildPipelines()
  * @param {GPUTextureView}    view       — current swap-chain texture view
  * @param {number}            slot       — frame & 1  (ping-pong selector)
+ * @param {boolean}           simulate   — run physics / splat / decay first
  */
 const DECAY_DISPATCH = (DENSITY_W * DENSITY_H) / 256;
 
-export function encodeFrame(enc, pipelines, view, slot) {
-    const { physicsPipeline, splatPipeline, decayPipeline, renderPipeline,
-            physicsBGs, splatBGs, decayBG, renderBG } = pipelines;
+export function encodeFrame(enc, pipelines, view, slot, simulate = true) {
+    if (simulate) encodeSimulation(enc, pipelines, slot);
+    encodeRender(enc, pipelines, view);
+}
+
+function encodeSimulation(enc, pipelines, slot) {
+    const { physicsPipeline, splatPipeline, decayPipeline,
+            physicsBGs, splatBGs, decayBG } = pipelines;
 
     // Physics
     const cp = enc.beginComputePass({ label: 'physics' });
@@ -173,6 +184,10 @@ export function encodeFrame(enc, pipelines, view, slot) {
     dp.setBindGroup(0, decayBG);
     dp.dispatchWorkgroups(DECAY_DISPATCH);
     dp.end();
+}
+
+function encodeRender(enc, pipelines, view) {
+    const { renderPipeline, renderBG } = pipelines;
 
     // Render
     const rp = enc.beginRenderPass({
@@ -0,0 +1,131 @@
+/**
+ * render-params.js — RenderParams uniform: layout, CPU mirror and writes.
+ *
+ * Single responsibility: own the Float32Array mirror of render.wgsl's
+ * `RenderParams` and every upload of it to `renderBuf`.  Callers set colours,
+ * camera and opacity through the setters; nothing else indexes the array.
+ *
+ * Layout (f32 offsets — must match RenderParams in render.wgsl)
+ * ──────
+ *   0  grad_start.rgba     4  grad_end.rgba      8  grad_box (min.xy, max.xy)
+ *  12  grad_axis          13  alpha             14  palette     15  falloff
+ *  16  bg_top.rgba        20  bg_bottom.rgba    24  camera (offset.xy, zoom, _)
+ *  28  pal_dim.rgba       32  pal_bright.rgba
+ */
+
+const GRAD_START = 0;
+const GRAD_END   = 4;
+const GRAD_BOX   = 8;
+const GRAD_AXIS  = 12;
+const ALPHA      = 13;
+const PALETTE    = 14;
+const FALLOFF    = 15;
+const BG_TOP     = 16;
+const BG_BOTTOM  = 20;
+const CAMERA     = 24;
+const PAL_DIM    = 28;
+const PAL_BRIGHT = 32;
+
+const RENDER_FLOATS = 36;
+export const RENDER_PARAMS_BYTES = RENDER_FLOATS * 4;   // 144
+
+/**
+ * @param {GPUDevice} device
+ * @param {GPUBuffer} renderBuf   RenderParams uniform (RENDER_PARAMS_BYTES)
+ */
+export function createRenderParams(device, renderBuf) {
+    const data   = new Float32Array(RENDER_FLOATS);
+    const camera = data.subarray(CAMERA, CAMERA + 4);   // rewritten every frame
+    camera[2]    = 1.0;                                  // zoom
+    data[ALPHA]  = 1.0;
+
+    const write = () => device.queue.writeBuffer(renderBuf, 0, data);
+    write();
+
+    return {
+        /** Current glow opacity in [0, 1]. */
+        get alpha() { return data[ALPHA]; },
+
+        /** Opacity of the whole glow, clamped to [0, 1].  Default 1. */
+        setAlpha(a) {
+            data[ALPHA] = Math.min(Math.max(a, 0), 1);
+            device.queue.writeBuffer(renderBuf, ALPHA * 4, data, ALPHA, 1);
+        },
+
+        /**
+         * Colour atoms by where they sit in the current shape: `start` at the
+         * left / bottom / centre, `end` at the right / top / rim.
+         *
+         * @param {number[]} start  rgb in [0,1]
+         * @param {number[]} end    rgb in [0,1]
+         * @param {number}   axis   0 off, 1 horizontal, 2 vertical, 3 radial
+         */
+        setGradient(start, end, axis) {
+            data.set(start, GRAD_START);
+            data.set(end,   GRAD_END);
+            data[GRAD_AXIS] = axis;
+            write();
+        },
+
+        /** Fit the gradient box to the bounding box of `targets` (interleaved xy). */
+        fitGradient(targets) {
+            let x0 = Infinity, y0 = Infinity, x1 = -Infinity, y1 = -Infinity;
+            for (let i = 0; i < targets.length; i += 2) {
+                const x = targets[i], y = targets[i + 1];
+                if (x < x0) x0 = x;
+                if (x > x1) x1 = x;
+                if (y < y0) y0 = y;
+                if (y > y1) y1 = y;
+            }
+            data.set([x0, y0, x1, y1], GRAD_BOX);
+            write();
+        },
+
+        /**
+         * Recolour the glow by brightness: `dim` for faint trails, `bright` for
+         * dense ones.  Mode 0 restores the phosphor ramp, 2 is the rainbow wheel.
+         *
+         * @param {number}   mode    0 phosphor, 1 ramp, 2 rainbow
+         * @param {number[]} dim     rgb in [0,1]
+         * @param {number[]} bright  rgb in [0,1]
+         */
+        setPalette(mode, dim, bright) {
+            data[PALETTE] = mode;
+            data.set(dim,    PAL_DIM);
+            data.set(bright, PAL_BRIGHT);
+            write();
+        },
+
+        /**
+         * Dim the glow with distance from the current shape's centre (the
+         * gradient box), leaving the core at full strength.
+         *
+         * @param {number} mode  0 off, 1 linear, 2 inverse-square, 3 Gaussian
+         */
+        setFalloff(mode) {
+            data[FALLOFF] = mode;
+            write();
+        },
+
+        /**
+         * Background behind the atoms — a vertical gradient from `bottom` to
+         * `top`; pass the same colour twice for a flat fill.
+         *
+         * @param {number[]} top     rgb in [0,1]
+         * @param {number[]} bottom  rgb in [0,1]
+         */
+        setBackground(top, bottom = top) {
+            data.set(top,    BG_TOP);
+            data.set(bottom, BG_BOTTOM);
+            write();
+        },
+
+        /** View offset and zoom (see ui/camera.js).  Uploads only the camera slot. */
+        setCamera(x, y, zoom) {
+            camera[0] = x;
+            camera[1] = y;
+            camera[2] = zoom;
+            device.queue.writeBuffer(renderBuf, camera.byteOffset, camera);
+        },
+    };
+}
@@ -22,21 +22,130 @@ import { initDevice }                    from './gpu/device.js';
 import { allocateBuffers, seedAtoms,
          N, DENSITY_BYTES, VEL_BYTES }  from './gpu/buffers.js';
 import { buildPipelines, encodeFrame }   from './gpu/pipelines.js';
+import { createRenderParams }            from './gpu/render-params.js';
 import { buildNCA, runNCA }              from './gpu/nca.js';
 import { buildOTGpu, assignTargetsGpu }  from './gpu/ot_gpu.js';
-import { getShape, resolveShape,
-         sampleFromDensity, SHAPE_NAMES } from './shapes/registry.js';
-import { initPanel, tickFPS,
-         setStatus, setPhase,
-         showResponse }                  from './ui/panel.js';
+import { getShape, resolveShape, defineImage, blendParts,
+         sampleFromDensity, fitToAspect, snapToGrid, motionFor,
+         SHAPE_NAMES }                   from './shapes/registry.js';
+import { imageDensity }                  from './shapes/image.js';
+import { random, SEED }                  from './random.js';
+import { queryParam }                    from './query.js';
+import { EASINGS, ease }                 from './easing.js';
+import { emit }                          from './events.js';
+import { BENCHMARK, createBenchmark }    from './benchmark.js';
+import { initPanel, initKeys, tickFPS, toggleFPS,
+         setStatus, setPhase, setMorph,
+         setColour, setPrompt,
+         showResponse, showToast }       from './ui/panel.js';
+import { createHistory }                 from './ui/history.js';
+import { initPointer, POINTER_RADIUS }   from './ui/pointer.js';
+import { initCamera }                    from './ui/camera.js';
+import { ndcToWorld }                    from './ui/coords.js';
+import { saveFrame, startRecording }     from './ui/capture.js';
+import { parseScript, playScript }      from './ui/script.js';
 
 
 // ── Constants ─────────────────────────────────────────────────────────────────
 
-const MORPH_DURATION  = 2.0;    // seconds: source → target travel
+const RECORD_FPS      = 60;     // max frames per second pushed to a clip
+const MORPH_DURATION  = 2.0;    // seconds: source → target travel (default)
 const HOLD_DURATION   = 3.5;    // seconds: pause at target before auto-advance
 const AUTO_CYCLE      = [...SHAPE_NAMES];
 
+// Morph curves (see easing.js)
+const DEFAULT_EASING  = 'easeInOut';   // smoothstep — the original morph curve
+const MIN_DURATION    = 0.25;
+const MAX_DURATION    = 10.0;
+
+// Screen-edge behaviour — index is the `boundary` selector in physics.wgsl
+const BOUNDARIES      = ['none', 'clamp', 'bounce', 'wrap'];
+
+// Staggered starts cycled with T — order is the `stagger.y` selector in physics.wgsl
+const STAGGERS        = [
+    { name: 'off',    amount: 0.0, order: 0 },
+    { name: 'index',  amount: 0.6, order: 0 },   // atoms set off one after another
+    { name: 'ripple', amount: 0.6, order: 1 },   // outward from the focus
+];
+const MAX_STAGGER     = 0.9;
+
+// Crossfade (V): glow fades out while a new shape is computed, back in as it morphs
+const FADE_OUT        = 0.25;   // seconds, 1 → 0
+const FADE_IN         = 0.6;    // seconds, 0 → 1
+const FADE_EASING     = 'easeInOutQuad';
+
+// Burst (Space): atoms fly out from the pointer, fall, then spring back
+const BURST_DURATION  = 1.6;    // seconds airborne
+const BURST_SPEED     = 1.4;    // NDC/s, fastest launch speed
+
+// Pixel-art snapping (Q): lattice cell size as a fraction of the screen, 0 = off
+const SNAPS           = [0, 0.01, 0.025, 0.05];
+
+// Dropped prompt scripts: seconds to hold each shape after its morph
+const SCRIPT_DWELL    = 2.5;
+
+// Benchmark (?benchmark): seconds to stay on each shape after it settles
+const BENCH_DWELL     = 1.5;
+
+// Repeated GPU validation errors are toasted at most once per message per window
+const GPU_ERROR_MS    = 5000;
+
+// Pause (P) / step (N): a step advances one 60 Hz frame of simulated time
+const STEP_DT         = 1 / 60;
+
+// Spring easing — per-frame stiffness and velocity retention (60 fps baseline)
+const SPRING_K        = 0.08;
+const SPRING_DAMPING  = 0.85;
+const MAX_SPRING_K    = 0.5;
+const MAX_DAMPING     = 1.0;
+
+// Colour gradients cycled with G — laid across the current shape's bounds
+const GRADIENTS = [
+    { name: 'off',        axis: 0, start: [0, 0, 0],       end: [0, 0, 0]       },
+    { name: 'horizontal', axis: 1, start: [0.0, 0.9, 1.0], end: [1.0, 0.2, 0.8] },
+    { name: 'vertical',   axis: 2, start: [1.0, 0.5, 0.1], end: [0.3, 0.4, 1.0] },
+    { name: 'radial',     axis: 3, start: [1.0, 1.0, 0.6], end: [0.9, 0.1, 0.3] },
+];
+
+// Built-in gradient per shape, used until the user picks colours with G or L
+const SHAPE_GRADIENTS = {
+    spiral: 'radial', galaxy: 'radial', galaxy2: 'radial', phyllotaxis: 'radial',
+    rose: 'radial', rose3: 'radial', rose5: 'radial', gaussian: 'radial',
+    wave: 'horizontal', interference: 'horizontal', lissajous: 'horizontal',
+    dna: 'vertical', helix3d: 'vertical', nanotube: 'vertical', eiffeltower: 'vertical',
+};
+
+// Palettes cycled with L (or `?palette=<name>`) — recolour the glow by brightness.
+// mode 0 keeps the built-in phosphor ramp, 1 ramps dim → bright, 2 is a hue
+// wheel around the shape's centre.  A gradient (G) overrides the palette.
+const PALETTES = [
+    { name: 'phosphor', mode: 0, dim: [0, 0, 0],          bright: [0, 0, 0]          },
+    { name: 'sunset',   mode: 1, dim: [0.60, 0.05, 0.20], bright: [1.00, 0.80, 0.30] },
+    { name: 'ocean',    mode: 1, dim: [0.00, 0.15, 0.50], bright: [0.50, 0.95, 1.00] },
+    { name: 'mono',     mode: 1, dim: [0.90, 0.90, 0.90], bright: [0.90, 0.90, 0.90] },
+    { name: 'rainbow',  mode: 2, dim: [0, 0, 0],          bright: [0, 0, 0]          },
+];
+
+/** Palette named by `?palette=` in the URL, or the phosphor default. */
+function initialPalette() {
+    const raw = queryParam('palette');
+    if (raw === null) return PALETTES[0];
+    const p = PALETTES.find(p => p.name === raw.toLowerCase());
+    if (!p) console.warn(`[tofu] ignoring unknown ?palette=${raw}`);
+    return p ?? PALETTES[0];
+}
+
+// Radial falloffs cycled with O — glow fades with distance from the shape centre
+const FALLOFFS = ['off', 'linear', 'inverse-square', 'gaussian'];
+
+// Backgrounds cycled with B — [top, bottom] rgb, vertical gradient
+const BACKGROUNDS = [
+    { name: 'black',    top: [0, 0, 0],          bottom: [0, 0, 0]          },
+    { name: 'midnight', top: [0.00, 0.02, 0.08], bottom: [0.02, 0.06, 0.16] },
+    { name: 'dusk',     top: [0.10, 0.02, 0.12], bottom: [0.25, 0.08, 0.05] },
+    { name: 'paper',    top: [0.92, 0.91, 0.88], bottom: [0.85, 0.84, 0.80] },
+];
+
 // Pre-allocated zero buffers for per-frame clears
 const DENSITY_CLEAR = new Uint8Array(DENSITY_BYTES);
 const VEL_CLEAR     = new Uint8Array(VEL_BYTES);
@@ -61,7 +170,35 @@ async function main() {
     // ── GPU init ───────────────────────────────────────────────────────────────
     const { device, ctx, format } = await initDevice(canvas);
 
+    // A broken pass can raise the same error every frame — toast each distinct
+    // message at most once per GPU_ERROR_MS and count the repeats in between.
+    const gpuErrors = new Map();   // message → { last, repeats }
+    device.addEventListener('uncapturederror', e => {
+        const msg  = e.error.message;
+        const seen = gpuErrors.get(msg) ?? { last: -Infinity, repeats: 0 };
+        const now  = performance.now();
+        if (now - seen.last < GPU_ERROR_MS) {
+            seen.repeats++;
+        } else {
+            const more = seen.repeats ? ` (×${seen.repeats + 1})` : '';
+            showToast(`gpu: ${msg}${more}`);
+            seen.last    = now;
+            seen.repeats = 0;
+        }
+        gpuErrors.set(msg, seen);
+    });
+
+    // Nothing submitted after a loss would run: stop the loop and say so.
+    let deviceLost = false;
+    device.lost.then(info => {
+        deviceLost = true;
+        if (info.reason === 'destroyed') return;
+        setStatus('device lost');
+        showToast('gpu device lost — reload the page to restart');
+    });
+
     // ── Buffers ────────────────────────────────────────────────────────────────
+    console.log(`[tofu] ${N.toLocaleString()} atoms${SEED === null ? '' : `, seed ${SEED}`}`);
     const buffers  = allocateBuffers(device);
     const seedData = seedAtoms(device, buffers.atomBufs);
 
@@ -86,30 +223,251 @@ async function main() {
     const ot = await buildOTGpu(device);
 
     // ── Sim params (uniform buffer) ────────────────────────────────────────────
-    // [dt, time, has_targets, morph_t]
-    const simData = new Float32Array(4);
+    // [dt, time, has_targets, morph_t, easing, spring_k, damping, boundary,
+    //  pointer.x, pointer.y, pointer.radius, pointer.strength,
+    //  stagger.amount, stagger.order, stagger.focus.x, stagger.focus.y,
+    //  burst.origin.x, burst.origin.y, burst.age (< 0 = off), burst.speed,
+    //  motion.spin, motion.drift, motion.start, motion.aspect]
+    const SIM = {
+        dt: 0, time: 1, hasTargets: 2, morphT: 3,
+        easing: 4, springK: 5, damping: 6, boundary: 7,
+        pointerX: 8, pointerY: 9, pointerRadius: 10, pointerStrength: 11,
+        staggerAmount: 12, staggerOrder: 13, focusX: 14, focusY: 15,
+        burstX: 16, burstY: 17, burstAge: 18, burstSpeed: 19,
+        spin: 20, drift: 21, motionStart: 22, aspect: 23,
+    };
+    const simData = new Float32Array(24);
+    simData[SIM.burstAge]      = -1;
+    simData[SIM.aspect]        = 1;
+    simData[SIM.pointerRadius] = POINTER_RADIUS;
+
+    // ── Render params (uniform buffer, see gpu/render-params.js) ──────────────
+    const render = createRenderParams(device, buffers.renderBuf);
+    let   crossfade  = false;                          // toggled with V
+    let   fade       = 1;                              // crossfade progress, 0 = hidden
+    let   fitScreen  = false;                          // toggled with A
+    let   snap       = SNAPS[0];                       // cycled with Q
+    let   gradient   = GRADIENTS[0];
+    let   palette    = initialPalette();
+    let   background = BACKGROUNDS[0];
+    let   falloff    = FALLOFFS[0];
+    let   userColour = palette !== PALETTES[0];   // G, L or ?palette= stop suggestions
+
+    function applyGradient(g) {
+        gradient = g;
+        render.setGradient(g.start, g.end, g.axis);
+        refreshColourHUD();
+    }
+
+    /** Apply the shape's built-in gradient (or none) unless colours are user-chosen. */
+    function suggestColour(shape) {
+        if (userColour) return;
+        const axis = SHAPE_GRADIENTS[shape] ?? 'off';
+        const g    = GRADIENTS.find(g => g.name === axis);
+        if (g !== gradient) applyGradient(g);
+    }
+
+    function applyPalette(p) {
+        palette = p;
+        render.setPalette(p.mode, p.dim, p.bright);
+        refreshColourHUD();
+    }
+
+    function applyBackground(b) {
+        background = b;
+        render.setBackground(b.top, b.bottom);
+        refreshColourHUD();
+    }
+
+    function applyFalloff(curve) {
+        falloff = curve;
+        render.setFalloff(FALLOFFS.indexOf(curve));
+        refreshColourHUD();
+    }
+
+    function refreshColourHUD() {
+        const hue = gradient.axis ? `gradient · ${gradient.name}` : palette.name;
+        const fo  = falloff !== 'off' ? ` · ${falloff}` : '';
+        setColour(`${hue} · ${background.name}${fo}`);
+    }
+
+    render.fitGradient(cpuTarget);
+    applyGradient(gradient);
+    applyPalette(palette);
+    applyBackground(background);
+
+    // ── Pointer force (drag = pull, Shift+drag = push) ─────────────────────────
+    const pointer = initPointer(canvas);
+
+    // ── Camera (wheel = zoom, middle-drag = pan) ───────────────────────────────
+    const camera = initCamera(canvas);
 
     // ── Morph state ────────────────────────────────────────────────────────────
-    const morph = { t: 0.0, hold: 0.0 };
+    const morph = {
+        t: 0.0, hold: 0.0,
+        duration: MORPH_DURATION, easing: DEFAULT_EASING,
+        springK:  SPRING_K,       damping: SPRING_DAMPING,
+        boundary: 'none', stagger: STAGGERS[0],
+    };
     let userControlled  = false;
     let shapeIdx        = -1;
     let transitioning   = false;   // true while NCA is running (prevents overlap)
+    let pending         = null;    // latest request made while transitioning
+
+    /**
+     * Set how long a morph takes and which curve it follows.
+     * Applies to the in-flight morph as well as every later one.
+     *
+     * @param {number} duration  seconds, clamped to [MIN_DURATION, MAX_DURATION]
+     * @param {string} easing    one of EASINGS
+     */
+    function setTransition(duration, easing) {
+        if (!EASINGS.includes(easing)) {
+            console.warn(`[morph] unknown easing "${easing}" — keeping ${morph.easing}`);
+            easing = morph.easing;
+        }
+        if (!Number.isFinite(duration)) duration = morph.duration;
+
+        morph.duration = Math.min(Math.max(duration, MIN_DURATION), MAX_DURATION);
+        morph.easing   = easing;
+        simData[SIM.easing] = EASINGS.indexOf(easing);
+        refreshMorphHUD();
+    }
+
+    /**
+     * Spring easing stiffness — fraction of the remaining distance added to
+     * velocity each 60 fps frame.  Negative / NaN values are ignored.
+     */
+    function setSpringStrength(k) {
+        if (!Number.isFinite(k) || k < 0) {
+            console.warn(`[morph] ignoring invalid spring strength ${k}`);
+            return;
+        }
+        morph.springK = Math.min(k, MAX_SPRING_K);
+        simData[SIM.springK] = morph.springK;
+        refreshMorphHUD();
+    }
+
+    /**
+     * Spring easing damping — fraction of velocity retained each 60 fps frame
+     * (1 = undamped, 0 = no momentum).  Negative / NaN values are ignored.
+     */
+    function setDamping(d) {
+        if (!Number.isFinite(d) || d < 0) {
+            console.warn(`[morph] ignoring invalid damping ${d}`);
+            return;
+        }
+        morph.damping = Math.min(d, MAX_DAMPING);
+        simData[SIM.damping] = morph.damping;
+        refreshMorphHUD();
+    }
+
+    /**
+     * What atoms do at the screen edge: 'none' (default), 'clamp', 'bounce'
+     * or 'wrap'.  Unknown modes are logged and ignored.
+     */
+    function setBoundary(mode) {
+        if (!BOUNDARIES.includes(mode)) {
+            console.warn(`[physics] unknown boundary "${mode}" — keeping ${morph.boundary}`);
+            return;
+        }
+        morph.boundary = mode;
+        simData[SIM.boundary] = BOUNDARIES.indexOf(mode);
+        refreshMorphHUD();
+    }
+
+    /**
+     * Stagger when atoms set off: each waits up to `amount` of the morph
+     * (clamped to [0, MAX_STAGGER]) before moving, ordered by atom index
+     * (order 0) or by its target's distance from `focus` (order 1, a ripple).
+     */
+    function setStagger(amount, order = 1, focus = [0, 0]) {
+        if (!Number.isFinite(amount) || amount < 0) {
+            console.warn(`[morph] ignoring invalid stagger ${amount}`);
+            return;
+        }
+        simData[SIM.staggerAmount] = Math.min(amount, MAX_STAGGER);
+        simData[SIM.staggerOrder]  = order;
+        simData[SIM.focusX]        = focus[0];
+        simData[SIM.focusY]        = focus[1];
+    }
+
+    function applyStagger(st) {
+        morph.stagger = st;
+        setStagger(st.amount, st.order);
+        refreshMorphHUD();
+    }
+
+    function refreshMorphHUD() {
+        const curve = morph.easing === 'spring'
+            ? `spring · k ${morph.springK.toFixed(3)} · d ${morph.damping.toFixed(2)}`
+            : `${morph.easing} · ${morph.duration.toFixed(2)}s`;
+        const extras = [
+            morph.stagger.amount > 0   ? morph.stagger.name : null,
+            morph.boundary !== 'none' ? morph.boundary     : null,
+        ].filter(Boolean);
+        setMorph([curve, ...extras].join(' · '));
+    }
+
+    setTransition(MORPH_DURATION, DEFAULT_EASING);
+    setSpringStrength(SPRING_K);
+    setDamping(SPRING_DAMPING);
 
     // ── Core transition primitive ──────────────────────────────────────────────
 
+    /**
+     * Keep the current targets moving: `spin` rad/s about the centre, `drift`
+     * NDC/s to the right (wrapping).  Both 0 = a still shape.  Motion is
+     * timed from this call, so a new shape starts unrotated.
+     */
+    function setMotion(spin, drift) {
+        simData[SIM.spin]        = spin;
+        simData[SIM.drift]       = drift;
+        simData[SIM.motionStart] = totalSec;
+        simData[SIM.aspect]      = fitScreen ? canvas.width / canvas.height : 1;
+    }
+
+    /**
+     * Stop any motion where it is: bake the current spin / drift into the
+     * targets (same transform as animate() in physics.wgsl) so the next
+     * transition starts from where the atoms actually are.
+     */
+    function freezeMotion() {
+        const [spin, drift, start, aspect] = simData.subarray(SIM.spin, SIM.aspect + 1);
+        if (spin === 0 && drift === 0) return;
+        const age = totalSec - start;
+        const c   = Math.cos(spin * age);
+        const s   = Math.sin(spin * age);
+        for (let i = 0; i < cpuTarget.length; i += 2) {
+            const x = cpuTarget[i] * aspect;
+            const y = cpuTarget[i + 1];
+            let   px = (c * x - s * y) / aspect;
+            if (drift !== 0) {
+                const u = px + 1 + drift * age;
+                px = u - 2 * Math.floor(u / 2) - 1;
+            }
+            cpuTarget[i]     = px;
+            cpuTarget[i + 1] = s * x + c * y;
+        }
+        device.queue.writeBuffer(buffers.targetBuf, 0, cpuTarget);
+        setMotion(0, 0);
+    }
+
     // newTargets: pre-filter OT result (used to track cpuTarget for next morph).
     // targetBuf on GPU is already written by the freeze_filter shader — no write here.
     function goToPositions(newTargets, label) {
         cpuSource.set(cpuTarget);
         cpuTarget.set(newTargets);
+        render.fitGradient(cpuTarget);
 
         device.queue.writeBuffer(buffers.sourceBuf, 0, cpuSource);
         // targetBuf already written by freeze_filter shader
 
-        morph.t    = 0.0;
-        morph.hold = 0.0;
-        simData[2] = 1.0;
-        simData[3] = 0.0;
+        morph.t     = 0.0;
+        morph.hold  = 0.0;
+        simData[SIM.hasTargets] = 1.0;
+        simData[SIM.morphT]     = 0.0;
+        simData[SIM.burstAge]   = -1;   // a new shape cancels any burst
 
         setStatus(label);
     }
@@ -119,81 +477,381 @@ async function main() {
      * sample N targets, compute OT assignment, then trigger a morph.
      *
      * Async because NCA requires a GPU→CPU readback (mapAsync).
-     * The `transitioning` flag prevents concurrent calls.
+     * The `transitioning` flag prevents concurrent calls: a request made while
+     * busy waits in a single `pending` slot and runs when the current one
+     * finishes.  A newer request replaces it, and the replaced call resolves
+     * to null — so a burst of prompts settles on the last one only.
+     *
+     * Only `typed` prompts (just entered by the user) read motion words;
+     * auto-cycle, history and script replays arrive still.
      */
-    async function goToShape(name) {
-        if (transitioning) return null;
+    async function goToShape(name, typed = false) {
+        if (transitioning) {
+            pending?.resolve(null);
+            return new Promise((resolve, reject) => { pending = { name, typed, resolve, reject }; });
+        }
         transitioning = true;
+        const started = performance.now();
+        emit('transition_start', { prompt: name });
 
         try {
+            freezeMotion();
             const canonical = resolveShape(name);
+            const blend     = blendParts(canonical);
+
+            let otResult;
+            if (blend) {
+                // Shape-space blend: B is assigned against A's targets, so
+                // atom i holds a matched pair and the lerp stays coherent.
+                // Neither build touches the live targetBuf (the hold spring
+                // would chase A mid-build); it is written once, lerped.
+                const a = await buildTargets(blend.a, cpuTarget, null);
+                const b = await buildTargets(blend.b, a, null);
+                otResult = new Float32Array(a.length);
+                for (let i = 0; i < a.length; i++) {
+                    otResult[i] = a[i] + (b[i] - a[i]) * blend.t;
+                }
+                device.queue.writeBuffer(buffers.targetBuf, 0, otResult);
+            } else {
+                otResult = await buildTargets(canonical, cpuTarget);
+            }
 
-            // ── Phase 2: parametric blueprint (CPU) ──────────────────────────
-            const goalGrid = getShape(canonical);
-
-            // ── Phase 3: NCA growth (GPU, 64 steps) ─────────────────────────
-            setPhase('nca · growing');
-            const organicDensity = await runNCA(device, nca, goalGrid);
-
-            // ── Sampling + GPU OT + freeze filter (all on GPU) ───────────────
-            // cpuTarget = current resting positions, used as OT source.
-            // assignTargetsGpu writes the freeze-filtered result directly into
-            // targetBuf on GPU; returns the pre-filter CPU array for cpuTarget tracking.
-            setPhase('ot · k-means');
-            const rawTgt  = sampleFromDensity(organicDensity);
-            const otResult = await assignTargetsGpu(device, ot, cpuTarget, rawTgt, buffers.targetBuf);
-
+            const { spin, drift } = typed ? motionFor(name) : { spin: 0, drift: 0 };
+            setMotion(spin, drift);
             goToPositions(otResult, canonical);
+            suggestColour(canonical);
+            emit('transition_done', {
+                prompt: name, shape: canonical,
+                ms: Math.round(performance.now() - started), points: N,
+            });
             return canonical;
 
+        } catch (e) {
+            emit('transition_error', { prompt: name, error: e.message });
+            throw e;
         } finally {
             transitioning = false;
+            if (pending) {
+                const { name: next, typed: nextTyped, resolve, reject } = pending;
+                pending = null;
+                goToShape(next, nextTyped).then(resolve, reject);
+            }
         }
     }
 
+    /**
+     * Grow `name` through NCA, sample N targets from it and OT-assign them to
+     * the atoms resting at `source`.  The freeze-filtered result goes to
+     * `targetBuf` (null: not written).  Returns the pre-filter assignment.
+     */
+    async function buildTargets(name, source, targetBuf = buffers.targetBuf) {
+        // ── Phase 2: parametric blueprint (CPU) ──────────────────────────────
+        const goalGrid = getShape(name);
+
+        // ── Phase 3: NCA growth (GPU, 64 steps) ─────────────────────────────
+        setPhase('nca · growing');
+        const organicDensity = await runNCA(device, nca, goalGrid);
+
+        // ── Sampling + GPU OT + freeze filter (all on GPU) ───────────────────
+        // assignTargetsGpu writes the freeze-filtered result directly into
+        // targetBuf on GPU; returns the pre-filter CPU array for cpuTarget tracking.
+        setPhase('ot · k-means');
+        const rawTgt = sampleFromDensity(organicDensity);
+        if (fitScreen) fitToAspect(rawTgt, canvas.width / canvas.height);
+        if (snap > 0)  snapToGrid(rawTgt, snap);
+        return assignTargetsGpu(device, ot, source, rawTgt, targetBuf);
+    }
+
+    /**
+     * Blow the current shape apart from `origin` (NDC world).  Atoms fly for
+     * BURST_DURATION seconds, then the hold spring gathers them back.  Flights
+     * launch from the targets, so a burst is ignored while a morph is still in
+     * flight (atoms mid-morph would jump to their targets).  Returns whether
+     * it fired.
+     */
+    function burst(origin, speed = BURST_SPEED) {
+        if (simData[SIM.hasTargets] < 0.5) return false;   // nothing to regroup into yet
+        if (morph.t < 1) return false;                      // mid-morph
+        simData[SIM.burstX]     = origin[0];
+        simData[SIM.burstY]     = origin[1];
+        simData[SIM.burstAge]   = 0.0;
+        simData[SIM.burstSpeed] = speed;
+        return true;
+    }
+
     /** Auto-cycle helper. */
     function advanceCycle() {
         shapeIdx = (shapeIdx + 1) % AUTO_CYCLE.length;
-        goToShape(AUTO_CYCLE[shapeIdx]);   // fire-and-forget (async)
+        goToShape(AUTO_CYCLE[shapeIdx])    // fire-and-forget (async)
+            .catch(e => { console.error(e); showToast(e.message); });
+    }
+
+    // Prompts that produced a shape, newest last (persisted; ↑ / ↓ replay)
+    const history = createHistory();
+
+    /**
+     * User-chosen shape: morph to it and stop auto-cycling.
+     * Typed prompts are remembered, and read motion words, unless `remember`
+     * is false (history / script replay).
+     */
+    async function pickShape(input, remember = true) {
+        let name;
+        try {
+            name = await goToShape(input, remember);
+        } catch (e) {
+            console.error(e);
+            showToast(`could not build "${input}": ${e.message}`);
+            return;
+        }
+        if (name !== null) {
+            userControlled = true;
+            showResponse(name);
+            if (remember && !input.startsWith('image:')) history.push(input.trim());
+        }
+    }
+
+    /** Re-apply a history entry without re-recording it. */
+    function replay(entry) {
+        if (entry === null) return;
+        setPrompt(entry);
+        pickShape(entry, false);
+    }
+
+    // Dropped prompt scripts — bumping `scriptRun` stops the one playing
+    let scriptRun = 0;
+
+    async function runScript(file) {
+        const prompts = parseScript(await file.text());
+        if (prompts.length === 0) {
+            showToast(`${file.name}: no prompts found`);
+            return;
+        }
+        const run = ++scriptRun;
+        showToast(`playing ${file.name} · ${prompts.length} prompts`);
+        await playScript(
+            prompts,
+            p => { setPrompt(p); return pickShape(p, false); },
+            () => (morph.duration + SCRIPT_DWELL) * 1000,
+            () => run !== scriptRun,
+        );
+    }
+
+    // ?benchmark — one timed pass through every shape, then a console table
+    let bench = BENCHMARK ? createBenchmark(SHAPE_NAMES, BENCH_DWELL * 1000) : null;
+    if (bench) window.addEventListener('tofu', e => {
+        if (e.detail.event === 'transition_done') bench?.built(e.detail.ms);
+    });
+
+    function benchStep() {
+        const shape = bench.next();
+        if (shape === null) {
+            console.table(bench.report());
+            showToast('benchmark done — results in the console');
+            bench = null;
+            return;
+        }
+        setPrompt(shape);
+        pickShape(shape, false);
     }
 
     // Start immediately with the first shape
-    advanceCycle();
+    if (bench) benchStep();
+    else       advanceCycle();
 
     // ── UI panel ───────────────────────────────────────────────────────────────
     initPanel({
-        async onSubmit(text) {
-            const name = await goToShape(text);
-            if (name !== null) {
-                userControlled = true;
-                showResponse(name);
-            }
+        onSubmit(text) {
+            scriptRun++;
+            pickShape(text);
         },
         onClear() {
+            scriptRun++;
             userControlled = false;
             advanceCycle();
         },
+        async onImage(file) {
+            try {
+                pickShape(defineImage(file.name, await imageDensity(file)));
+            } catch (e) {
+                console.error(e);
+                showToast(`could not read ${file.name}: ${e.message}`);
+            }
+        },
+        onScript(file) {
+            runScript(file).catch(e => {
+                console.error(e);
+                showToast(`could not play ${file.name}: ${e.message}`);
+            });
+        },
+        history,
+    });
+
+    // ── Keyboard shortcuts (inactive while typing in the prompt box) ───────────
+    initKeys({
+        // 1–6 — preset shapes (6 picks one at random)
+        Digit1() { pickShape('circle'); },
+        Digit2() { pickShape('grid');   },
+        Digit3() { pickShape('dna');    },
+        Digit4() { pickShape('spiral'); },
+        Digit5() { pickShape('wave');   },
+        Digit6() { pickShape(SHAPE_NAMES[Math.floor(random() * SHAPE_NAMES.length)]); },
+        // ↑ / ↓ — step back / forward through prompt history
+        ArrowUp()   { replay(history.prev()); },
+        ArrowDown() { replay(history.next()); },
+        // E — cycle morph easing curve
+        KeyE() {
+            const next = EASINGS[(EASINGS.indexOf(morph.easing) + 1) % EASINGS.length];
+            setTransition(morph.duration, next);
+        },
+        // [ / ] — faster / slower morphs
+        BracketLeft()  { setTransition(morph.duration * 0.8,  morph.easing); },
+        BracketRight() { setTransition(morph.duration * 1.25, morph.easing); },
+        // J / K — softer / stiffer spring,  D / F — more / less damping
+        KeyJ() { setSpringStrength(Math.max(morph.springK - 0.01, 0)); },
+        KeyK() { setSpringStrength(morph.springK + 0.01); },
+        KeyD() { setDamping(Math.max(morph.damping - 0.02, 0)); },
+        KeyF() { setDamping(morph.damping + 0.02); },
+        // T — cycle staggered starts
+        KeyT() {
+            applyStagger(STAGGERS[(STAGGERS.indexOf(morph.stagger) + 1) % STAGGERS.length]);
+        },
+        // Space — burst from the pointer
+        Space() {
+            const w = ndcToWorld(pointer, camera);
+            if (!burst([w.x, w.y])) showResponse('burst · wait for the shape to settle');
+        },
+        // W — cycle screen-edge behaviour
+        KeyW() {
+            setBoundary(BOUNDARIES[(BOUNDARIES.indexOf(morph.boundary) + 1) % BOUNDARIES.length]);
+        },
+        // L — cycle colour palette
+        KeyL() {
+            userColour = true;
+            applyPalette(PALETTES[(PALETTES.indexOf(palette) + 1) % PALETTES.length]);
+        },
+        // G — cycle colour gradient
+        KeyG() {
+            userColour = true;
+            applyGradient(GRADIENTS[(GRADIENTS.indexOf(gradient) + 1) % GRADIENTS.length]);
+        },
+        // B — cycle background
+        KeyB() {
+            applyBackground(BACKGROUNDS[(BACKGROUNDS.indexOf(background) + 1) % BACKGROUNDS.length]);
+        },
+        // O — cycle radial falloff
+        KeyO() {
+            applyFalloff(FALLOFFS[(FALLOFFS.indexOf(falloff) + 1) % FALLOFFS.length]);
+        },
+        // C — reset the camera
+        KeyC() { camera.reset(); },
+        // V — toggle crossfading between shapes
+        KeyV() {
+            crossfade = !crossfade;
+            showResponse(`crossfade ${crossfade ? 'on' : 'off'}`);
+        },
+        // A — toggle keeping shape proportions on non-square windows
+        KeyA() {
+            fitScreen = !fitScreen;
+            showResponse(`fit to screen ${fitScreen ? 'on' : 'off'} · next shape`);
+        },
+        // P — pause / resume the simulation
+        KeyP() {
+            paused = !paused;
+            if (paused) setPhase('paused');
+            showResponse(paused ? 'paused · N steps one frame' : 'resumed');
+        },
+        // N — step one frame (pauses first if running)
+        KeyN() {
+            paused   = true;
+            stepOnce = true;
+        },
+        // Q — cycle pixel-art snapping of targets
+        KeyQ() {
+            snap = SNAPS[(SNAPS.indexOf(snap) + 1) % SNAPS.length];
+            showResponse(`snap ${snap > 0 ? `${snap * 100}% of screen` : 'off'} · next shape`);
+        },
+        // H — show / hide the render readout (fps · frame ms · cpu ms)
+        KeyH() { showResponse(toggleFPS() ? 'render readout on' : 'render readout off'); },
+        // S — save the next frame as a PNG
+        KeyS() { screenshot = true; },
+        // R — start / stop recording a WebM clip
+        KeyR() {
+            if (recording) {
+                recording.stop();
+                recording = null;
+                showResponse('clip saved');
+            } else {
+                recording = startRecording(canvas, RECORD_FPS);
+                showResponse('● recording — R to stop');
+            }
+        },
     });
 
     // ── Frame loop ─────────────────────────────────────────────────────────────
 
-    let frame    = 0;
-    let lastMs   = performance.now();
-    let totalSec = 0;
+    let frame      = 0;
+    let lastMs     = performance.now();
+    let totalSec   = 0;
+    let screenshot = false;   // capture after the next submit
+    let recording  = null;    // active clip recorder, see capture.js
+    let cpuMs      = 0;       // JS time spent in the previous tick
+    let paused     = false;   // P — freeze the simulation, keep drawing
+    let stepOnce   = false;   // N — advance one frame while paused
 
     function tick() {
+        if (deviceLost) return;
         const nowMs = performance.now();
-        const dt    = Math.min((nowMs - lastMs) / 1000, 0.033);
+        // Clips are stamped with wall-clock time, so while recording the
+        // simulation follows real time up to 100 ms (a lagging GPU would otherwise
+        // play back slow); a single step while paused is one 60 Hz frame
+        const wall  = (nowMs - lastMs) / 1000;
+        const dt    = paused    ? STEP_DT
+                    : recording ? Math.min(wall, 0.1)
+                                : Math.min(wall, 0.033);
+        const frameMs = nowMs - lastMs;
         lastMs      = nowMs;
-        totalSec   += dt;
 
-        tickFPS(nowMs);
+        tickFPS(nowMs, cpuMs);
+
+        const simulate = !paused || stepOnce;
+        stepOnce = false;
+        if (simulate) advance(dt);
+
+        if (bench && bench.frame(frameMs, morph.t >= 1 && !transitioning)) benchStep();
+
+        render.setCamera(camera.x, camera.y, camera.zoom);
+
+        // ── Encode + submit frame ───────────────────────────────────────────
+        // Paused: redraw the last frame's buffers (camera still applies)
+        const slot = frame & 1;
+        const enc  = device.createCommandEncoder();
+        encodeFrame(enc, pipelines, ctx.getCurrentTexture().createView(), slot, simulate);
+        device.queue.submit([enc.finish()]);
+
+        if (screenshot) {
+            screenshot = false;
+            saveFrame(canvas);
+        }
+        recording?.frame(nowMs);
+
+        cpuMs = performance.now() - nowMs;
+        if (simulate) frame++;
+        requestAnimationFrame(tick);
+    }
+
+    /** Advance the simulation clock and write this frame's sim params. */
+    function advance(dt) {
+        totalSec += dt;
 
         // ── Morph timing ────────────────────────────────────────────────────
-        if (simData[2] > 0.5) {
+        if (simData[SIM.hasTargets] > 0.5) {
             if (morph.t < 1.0) {
-                morph.t = Math.min(morph.t + dt / MORPH_DURATION, 1.0);
+                morph.t = Math.min(morph.t + dt / morph.duration, 1.0);
                 setPhase(`morph ${Math.round(morph.t * 100)}%`);
+            } else if (simData[SIM.burstAge] >= 0) {
+                simData[SIM.burstAge] += dt;
+                if (simData[SIM.burstAge] >= BURST_DURATION) simData[SIM.burstAge] = -1;
+                setPhase('burst');
             } else {
                 morph.hold += dt;
                 setPhase(`hold ${morph.hold.toFixed(1)}s`);
@@ -203,26 +861,30 @@ async function main() {
                     advanceCycle();
                 }
             }
-            simData[3] = morph.t;
+            simData[SIM.morphT] = morph.t;
+        }
+
+        // ── Crossfade ───────────────────────────────────────────────────────
+        // `fade` ramps linearly; the glow's opacity follows it along FADE_EASING
+        const fading = crossfade && transitioning;
+        if (fading ? fade > 0 : fade < 1) {
+            fade = fading ? Math.max(fade - dt / FADE_OUT, 0)
+                          : Math.min(fade + dt / FADE_IN,  1);
+            render.setAlpha(ease(fade, FADE_EASING));
         }
 
         // ── Write sim params ────────────────────────────────────────────────
-        simData[0] = dt;
-        simData[1] = totalSec;
+        simData[SIM.dt]   = dt;
+        simData[SIM.time] = totalSec;
+        const pw    = ndcToWorld(pointer, camera);
+        simData[SIM.pointerX]        = pw.x;
+        simData[SIM.pointerY]        = pw.y;
+        simData[SIM.pointerStrength] = pointer.strength;
         device.queue.writeBuffer(buffers.simBuf, 0, simData);
 
         // ── Clear density + velocity buffers ────────────────────────────────
         device.queue.writeBuffer(buffers.densityBuf, 0, DENSITY_CLEAR);
         device.queue.writeBuffer(buffers.velBuf,     0, VEL_CLEAR);
-
-        // ── Encode + submit frame ───────────────────────────────────────────
-        const slot = frame & 1;
-        const enc  = device.createCommandEncoder();
-        encodeFrame(enc, pipelines, ctx.getCurrentTexture().createView(), slot);
-        device.queue.submit([enc.finish()]);
-
-        frame++;
-        requestAnimationFrame(tick);
     }
 
     requestAnimationFrame(tick);
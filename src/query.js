@@ -0,0 +1,21 @@
+/**
+ * query.js — Page URL query parameters.
+ *
+ * Single responsibility: read `?name=value` switches from the page URL.  Both
+ * helpers are safe outside a browser (node, workers without `location`),
+ * where every parameter is absent.
+ */
+
+const params = globalThis.location?.search
+    ? new URLSearchParams(globalThis.location.search)
+    : null;
+
+/** Value of `?name=`, or null when absent. */
+export function queryParam(name) {
+    return params?.get(name) ?? null;
+}
+
+/** True when `?name` is present, with or without a value. */
+export function hasQueryParam(name) {
+    return params?.has(name) ?? false;
+}
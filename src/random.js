@@ -0,0 +1,40 @@
+/**
+ * random.js — Shared pseudo-random source.
+ *
+ * Defaults to Math.random().  With `?seed=<integer>` in the URL every draw
+ * comes from a seeded mulberry32 stream instead, so the initial scatter,
+ * target sampling and NCA seed noise repeat exactly across reloads — given
+ * the same sequence of shapes.  `?benchmark` runs default to seed 1.
+ */
+
+import { queryParam, hasQueryParam } from './query.js';
+
+/** Mulberry32: tiny, fast, good enough for sampling.  Returns [0, 1). */
+export function mulberry32(seed) {
+    let a = seed >>> 0;
+    return function () {
+        a = (a + 0x6D2B79F5) >>> 0;
+        let t = a;
+        t = Math.imul(t ^ (t >>> 15), t | 1);
+        t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+        return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+    };
+}
+
+const BENCHMARK_SEED = 1;
+
+function seedFromURL() {
+    const raw = queryParam('seed');
+    if (raw === null) return hasQueryParam('benchmark') ? BENCHMARK_SEED : null;
+    const n = Number(raw);
+    if (!Number.isInteger(n)) {
+        console.warn(`[tofu] ignoring invalid ?seed=${raw}`);
+        return null;
+    }
+    return n;
+}
+
+export const SEED = seedFromURL();
+
+/** Uniform [0, 1) — seeded when `?seed=` is set, Math.random otherwise. */
+export const random = SEED === null ? Math.random : mulberry32(SEED);
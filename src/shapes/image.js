@@ -0,0 +1,58 @@
+/**
+ * image.js — Dropped images → density grid.
+ *
+ * The image is scaled to fit the usable extent with its aspect ratio kept,
+ * centred, and thresholded into a GRID_SIZE² density grid (row 0 = NDC
+ * y = -1).  Images with transparency are masked by alpha, so a dark logo on a
+ * transparent background still lights up; opaque images use luminance.
+ *
+ * Resolution is fixed by the grid, so large images are effectively
+ * downsampled and tiny ones upscaled — the importance sampler then spreads
+ * the full atom budget over whatever is lit.
+ */
+
+import { GRID_SIZE, gaussianBlur } from './primitives.js';
+
+const G      = GRID_SIZE;
+const EXTENT = 0.9;   // fraction of the grid the longer side fills
+
+/**
+ * @param {Blob}   file       any format createImageBitmap understands
+ * @param {number} threshold  luminance cut-off in [0, 255] for opaque images
+ * @returns {Promise<Float32Array>}  GRID_SIZE × GRID_SIZE, values in [0, 1]
+ */
+export async function imageDensity(file, threshold = 128) {
+    const bmp   = await createImageBitmap(file);
+    const scale = (G * EXTENT) / Math.max(bmp.width, bmp.height);
+    const w     = Math.max(1, Math.round(bmp.width  * scale));
+    const h     = Math.max(1, Math.round(bmp.height * scale));
+
+    const x0    = Math.floor((G - w) / 2);
+    const y0    = Math.floor((G - h) / 2);
+
+    const canvas = new OffscreenCanvas(G, G);
+    const ctx    = canvas.getContext('2d');
+    ctx.drawImage(bmp, x0, y0, w, h);
+    bmp.close();
+    const px = ctx.getImageData(0, 0, G, G).data;
+
+    // Any transparency inside the drawn area → use alpha as the mask
+    let masked = false;
+    for (let y = y0; y < y0 + h && !masked; y++) {
+        for (let x = x0; x < x0 + w; x++) {
+            if (px[(y * G + x) * 4 + 3] < 255) { masked = true; break; }
+        }
+    }
+
+    const raw = new Float32Array(G * G);
+    for (let y = 0; y < G; y++) {
+        const row = G - 1 - y;   // canvas row 0 is the top; grid row 0 is the bottom
+        for (let x = 0; x < G; x++) {
+            const i   = (y * G + x) * 4;
+            const lum = 0.2126 * px[i] + 0.7152 * px[i + 1] + 0.0722 * px[i + 2];
+            const on  = masked ? px[i + 3] >= 128 : px[i + 3] > 0 && lum >= threshold;
+            raw[row * G + x] = on ? 1 : 0;
+        }
+    }
+    return gaussianBlur(raw, G, G, 0.8);
+}
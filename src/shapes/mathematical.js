@@ -2,7 +2,8 @@
  * mathematical.js — Tier 2 parametric density generators.
  *
  * Shapes derived from mathematical structures: attractors, fractals, wave
- * phenomena, and parametric curves.  Every function returns a
+ * phenomena, parametric curves, and perspective-projected 3D surfaces.
+ * Every function returns a
  * Float32Array(GRID_SIZE²) with values in [0,1].
  * Row 0 = NDC y = -1 (bottom), consistent with primitives.js convention.
  */
@@ -13,14 +14,43 @@ const G = GRID_SIZE;
 
 // ── Shared helpers ─────────────────────────────────────────────────────────────
 
-/** Accumulate an array of [x,y] NDC points into a grid, then blur. */
-function rasterize(pts, sigma = 2.0) {
+/**
+ * Accumulate an array of [x,y] NDC points into a grid, then blur.  Each point
+ * adds 1, or `weights[i]` when given.
+ */
+function rasterize(pts, sigma = 2.0, weights = null) {
     const raw = new Float32Array(G * G);
-    for (const [x, y] of pts) {
+    for (let i = 0; i < pts.length; i++) {
+        const [x, y] = pts[i];
         const col = Math.round((x + 1) / 2 * (G - 1));
         const row = Math.round((y + 1) / 2 * (G - 1));
         if (row >= 0 && row < G && col >= 0 && col < G)
-            raw[row * G + col] += 1;
+            raw[row * G + col] += weights ? weights[i] : 1;
+    }
+    return gaussianBlur(raw, G, G, sigma);
+}
+
+// Fixed pinhole camera for 3D shapes: looks down −z from CAM_Z, scene tilted
+// by CAM_TILT about x so the top of a shape leans away from the viewer.
+const CAM_Z    = 3.0;
+const CAM_TILT = 0.35;
+
+/**
+ * Project [x,y,z] points (scene units ≈ NDC) through the fixed camera and
+ * accumulate them with depth-weighted density: nearer points add more weight
+ * (brighter, more atoms) and spread less, farther points fade.
+ */
+function rasterize3D(pts, sigma = 1.4) {
+    const raw = new Float32Array(G * G);
+    const c = Math.cos(CAM_TILT), s = Math.sin(CAM_TILT);
+    for (const [x, y0, z0] of pts) {
+        const y = y0 * c - z0 * s;
+        const z = y0 * s + z0 * c;
+        const k = (CAM_Z - 1) / (CAM_Z - z);     // 1 at z = 1 plane, < 1 behind it
+        const col = Math.round((x * k + 1) / 2 * (G - 1));
+        const row = Math.round((y * k + 1) / 2 * (G - 1));
+        if (row >= 0 && row < G && col >= 0 && col < G)
+            raw[row * G + col] += k * k * k;     // depth cue: far → faint
     }
     return gaussianBlur(raw, G, G, sigma);
 }
@@ -38,7 +68,9 @@ function pushSegment(arr, x1, y1, x2, y2, n = 24) {
 
 /**
  * Lissajous figure: x = sin(a·t + δ),  y = sin(b·t).
- * Default a=3, b=2, δ=π/4 gives a classic pretzel knot.
+ * Default a=3, b=2, δ=π/4 gives a classic pretzel knot; a=1, b=2, δ=0 is the
+ * figure-eight crossing the centre.  Scaled to ±0.82 so every ratio keeps a
+ * margin inside the screen.
  */
 export function lissajous(a = 3, b = 2, delta = Math.PI / 4) {
     const STEPS = 10000;
@@ -55,14 +87,17 @@ export function lissajous(a = 3, b = 2, delta = Math.PI / 4) {
 
 /**
  * Lorenz attractor, X-Z projection (the classic butterfly silhouette).
- * σ=10, ρ=28, β=8/3 — standard chaotic parameters.
+ * Defaults σ=10, ρ=28, β=8/3 are the standard chaotic parameters; the
+ * trajectory is fitted to the frame whatever they are.  Parameters that
+ * settle onto a fixed point (ρ ≤ 1, or non-chaotic ρ) draw the approach to it
+ * instead, weighted by arc length so the path is lit evenly rather than piling
+ * up on the point; integration stops if a large dt diverges.
  */
-export function lorenz() {
-    const σ = 10, ρ = 28, β = 8 / 3, dt = 0.005;
+export function lorenz(σ = 10, ρ = 28, β = 8 / 3, dt = 0.005) {
     let lx = 0.1, ly = 0, lz = 0;
 
-    const WARMUP = 3000, STEPS = 60000;
-    const pts = [];
+    const WARMUP = 3000, STEPS = 60000, LIMIT = 1e6;
+    const pts = [];                                  // transient included
 
     for (let i = 0; i < WARMUP + STEPS; i++) {
         const dx = σ * (ly - lx);
@@ -71,21 +106,36 @@ export function lorenz() {
         lx += dx * dt;
         ly += dy * dt;
         lz += dz * dt;
-        if (i >= WARMUP) pts.push([lx, lz]);   // XZ → butterfly silhouette
+        if (!(Math.abs(lx) < LIMIT && Math.abs(lz) < LIMIT)) break;   // diverged / NaN
+        pts.push([lx, lz]);                          // XZ → butterfly silhouette
     }
 
-    // Fit to [-0.88, 0.88]
-    let minX = Infinity, maxX = -Infinity, minY = Infinity, maxY = -Infinity;
-    for (const [x, y] of pts) {
-        if (x < minX) minX = x; if (x > maxX) maxX = x;
-        if (y < minY) minY = y; if (y > maxY) maxY = y;
-    }
-    const span  = Math.max(maxX - minX, maxY - minY);
-    const scale = (0.88 * 2) / span;
-    const cx    = (minX + maxX) / 2;
-    const cy    = (minY + maxY) / 2;
+    // Fit to [-0.88, 0.88].  A run that settles (stays within 1 % of its final
+    // point for the last quarter or more) is fitted from the start, cut where
+    // it arrives; a chaotic one from the end of the warm-up.
+    const box = p => {
+        let minX = Infinity, maxX = -Infinity, minY = Infinity, maxY = -Infinity;
+        for (const [x, y] of p) {
+            if (x < minX) minX = x; if (x > maxX) maxX = x;
+            if (y < minY) minY = y; if (y > maxY) maxY = y;
+        }
+        return { span: Math.max(maxX - minX, maxY - minY),
+                 cx: (minX + maxX) / 2, cy: (minY + maxY) / 2 };
+    };
+    const all = box(pts);
+    if (!(all.span > 0)) throw new Error(`lorenz(${σ}, ${ρ}, ${β}, ${dt}) has no extent`);
+    const [ex, ey] = pts[pts.length - 1];
+    let end = pts.length - 1;
+    while (end > 0 && Math.hypot(pts[end][0] - ex, pts[end][1] - ey) < 0.01 * all.span) end--;
+    const settles = end < pts.length - STEPS / 4;
 
-    return rasterize(pts.map(([x, y]) => [(x - cx) * scale, (y - cy) * scale]), 1.2);
+    const fit   = settles ? pts.slice(0, end + 1) : pts.slice(WARMUP);
+    const b     = box(fit);
+    const scale = (0.88 * 2) / b.span;
+    const arc   = settles
+        ? fit.map((p, i) => i ? Math.hypot(p[0] - fit[i - 1][0], p[1] - fit[i - 1][1]) : 0)
+        : null;
+    return rasterize(fit.map(([x, y]) => [(x - b.cx) * scale, (y - b.cy) * scale]), 1.2, arc);
 }
 
 /**
@@ -186,17 +236,20 @@ export function rossler() {
 }
 
 /**
- * Rose curve: r = cos(k·θ).
- * k=4 → 8 petals.  Elegant radial symmetry, unlike any other shape here.
+ * Rose curve: r = cos(k·θ), scaled to `radius`.
+ * Integer k: odd k → k petals, even k → 2k petals (k=4 → 8, k=3 → 3).
+ * k=1 is a single loop through the centre.  Elegant radial symmetry,
+ * unlike any other shape here.
  */
-export function rose(k = 4) {
+export function rose(k = 4, radius = 0.82) {
     const STEPS = 12000;
     const pts   = [];
-    // Rose curve repeats every π (odd k) or 2π (even k); sample 2π to be safe
+    // Integer odd k retraces itself after π; everything else needs the full 2π
+    const span = Number.isInteger(k) && k % 2 === 1 ? Math.PI : 2 * Math.PI;
     for (let i = 0; i < STEPS; i++) {
-        const θ = (i / STEPS) * 2 * Math.PI;
+        const θ = (i / STEPS) * span;
         const r = Math.cos(k * θ);
-        pts.push([r * Math.cos(θ) * 0.82, r * Math.sin(θ) * 0.82]);
+        pts.push([r * Math.cos(θ) * radius, r * Math.sin(θ) * radius]);
     }
     return rasterize(pts, 1.8);
 }
@@ -204,8 +257,9 @@ export function rose(k = 4) {
 /**
  * Logarithmic spiral: r = a·e^(b·θ).
  * Tighter inner coil than the Archimedean spiral — more "galaxy arm" feel.
+ * `arms` copies are spaced 2π/arms apart; `clockwise` mirrors the winding.
  */
-export function logSpiral(b = 0.18, turns = 3.5, width = 0.055) {
+export function logSpiral(b = 0.18, turns = 3.5, width = 0.055, arms = 1, clockwise = false) {
     const G   = GRID_SIZE;
     const raw = new Float32Array(G * G);
 
@@ -215,14 +269,16 @@ export function logSpiral(b = 0.18, turns = 3.5, width = 0.055) {
             const y = (row / (G - 1)) * 2 - 1;
             const r = Math.hypot(x, y);
             if (r < 0.01 || r > 0.90) continue;
-            const θ   = ((Math.atan2(y, x) + 2 * Math.PI) % (2 * Math.PI));
+            const a   = clockwise ? -Math.atan2(y, x) : Math.atan2(y, x);
+            const θ   = ((a + 2 * Math.PI) % (2 * Math.PI));
             // How many wraps back does this angle correspond to?
             const logR = Math.log(r) / b;      // θ_0 for this radius
-            // Nearest spiral arm angle
+            const gap  = 2 * Math.PI / arms;   // angular spacing between arms
+            // Nearest spiral arm angle (modulo the arm spacing)
             for (let wrap = 0; wrap < turns; wrap++) {
                 const armTheta = logR + wrap * 2 * Math.PI;
-                const diff     = ((θ - (armTheta % (2 * Math.PI))) + 2 * Math.PI) % (2 * Math.PI);
-                const closest  = Math.min(diff, 2 * Math.PI - diff);
+                const diff     = (((θ - armTheta) % gap) + gap) % gap;
+                const closest  = Math.min(diff, gap - diff);
                 if (closest * r < width) { raw[row * G + col] = 1; break; }
             }
         }
@@ -230,6 +286,23 @@ export function logSpiral(b = 0.18, turns = 3.5, width = 0.055) {
     return gaussianBlur(raw, G, G, 1.6);
 }
 
+/**
+ * Phyllotaxis (sunflower seed head): seed i at angle i·137.5°, r = c·√i.
+ * The golden angle gives uniform density with no clumping or visible arms.
+ * `scale` sets the outer radius as a fraction of the default 0.82.
+ */
+export function phyllotaxis(seeds = 700, scale = 1.0) {
+    const golden = Math.PI * (3 - Math.sqrt(5));    // 137.507…°
+    const c      = (0.82 * scale) / Math.sqrt(seeds);
+    const pts    = [];
+    for (let i = 0; i < seeds; i++) {
+        const r = c * Math.sqrt(i);
+        const θ = i * golden;
+        pts.push([r * Math.cos(θ), r * Math.sin(θ)]);
+    }
+    return rasterize(pts, 0.8);
+}
+
 /**
  * Mandelbrot set (c-plane view).
  * The iconic cardioid + bulb silhouette.
@@ -254,3 +327,36 @@ export function mandelbrot(maxIter = 80) {
     }
     return gaussianBlur(raw, G, G, 0.7);
 }
+
+/**
+ * Sphere — Fibonacci-lattice points on a sphere surface, seen in perspective.
+ * Depth weighting makes the near hemisphere dense and the far side faint,
+ * which reads as a solid ball rather than a flat disc.
+ */
+export function sphere(r = 0.72, count = 9000) {
+    const pts = [];
+    const golden = Math.PI * (3 - Math.sqrt(5));
+    for (let i = 0; i < count; i++) {
+        const y  = 1 - (2 * (i + 0.5)) / count;
+        const rr = Math.sqrt(1 - y * y);
+        const θ  = golden * i;
+        pts.push([Math.cos(θ) * rr * r, y * r, Math.sin(θ) * rr * r]);
+    }
+    return rasterize3D(pts, 1.2);
+}
+
+/**
+ * 3D helix — a coil around the vertical axis in perspective.
+ * Unlike the side-on dna projection, the back of each turn fades and
+ * narrows so the coil visibly wraps in depth.
+ */
+export function helix3D(turns = 4, r = 0.45, height = 1.5) {
+    const STEPS = 12000;
+    const pts   = [];
+    for (let i = 0; i < STEPS; i++) {
+        const t = i / STEPS;
+        const θ = t * turns * 2 * Math.PI;
+        pts.push([Math.cos(θ) * r, (t - 0.5) * height, Math.sin(θ) * r]);
+    }
+    return rasterize3D(pts, 1.3);
+}
@@ -324,3 +324,122 @@ export function hexGrid(spacing = 0.18) {
     }
     return gaussianBlur(raw, G, G, 1.2);
 }
+
+
+/**
+ * Rectangular grid of dots, `rows` × `cols`.  With `square` the cell pitch is
+ * shared by both axes (a 2 × 8 grid is a wide banner); otherwise each axis
+ * stretches to fill the frame.
+ */
+export function dotGrid(rows = 6, cols = 6, square = true) {
+    const G      = GRID_SIZE;
+    const raw    = new Float32Array(G * G);
+    const extent = 1.6;                      // NDC span used by the outer cells
+    let   px     = extent / cols;
+    let   py     = extent / rows;
+    if (square) px = py = Math.min(px, py);
+    const r = Math.max(Math.min(px, py) * 0.3, 0.02);
+
+    for (let row = 0; row < G; row++) {
+        for (let col = 0; col < G; col++) {
+            const { x, y } = toNDC(col, row, G, G);
+            // Nearest node: cell centres sit at (i + 0.5 - cols/2) · pitch
+            const i = Math.min(Math.max(Math.floor(x / px + cols / 2), 0), cols - 1);
+            const j = Math.min(Math.max(Math.floor(y / py + rows / 2), 0), rows - 1);
+            const nx = (i + 0.5 - cols / 2) * px;
+            const ny = (j + 0.5 - rows / 2) * py;
+            raw[row * G + col] = Math.hypot(x - nx, y - ny) < r ? 1 : 0;
+        }
+    }
+    return gaussianBlur(raw, G, G, 1.0);
+}
+
+// ── Density functions ─────────────────────────────────────────────────────────
+
+/**
+ * Grid from a density function: `f(x, y)` is evaluated at every cell centre
+ * (NDC), negatives are clamped to 0 and the result is scaled to peak at 1.
+ * sampleFromDensity then places atoms in proportion to `f`.
+ *
+ * @param {(x: number, y: number) => number} f
+ * @returns {Float32Array}
+ */
+export function densityField(f) {
+    const G   = GRID_SIZE;
+    const out = new Float32Array(G * G);
+    let   mx  = 0;
+    for (let row = 0; row < G; row++) {
+        for (let col = 0; col < G; col++) {
+            const { x, y } = toNDC(col, row, G, G);
+            const v = Math.max(f(x, y), 0);
+            out[row * G + col] = v;
+            if (v > mx) mx = v;
+        }
+    }
+    if (mx > 0) for (let i = 0; i < out.length; i++) out[i] /= mx;
+    return out;
+}
+
+/** Soft Gaussian cloud: dense core thinning smoothly outward. */
+export function gaussianBlob(sigma = 0.3) {
+    const k = 1 / (2 * sigma * sigma);
+    return densityField((x, y) => Math.exp(-(x * x + y * y) * k));
+}
+
+/**
+ * Ring whose thickness swells from `minW` to `maxW` and back `lobes` times
+ * around the circle, with a Gaussian cross-section (one lobe = a crescent).
+ */
+export function swellRing(r = 0.6, minW = 0.03, maxW = 0.28, lobes = 1) {
+    return densityField((x, y) => {
+        const a = Math.atan2(y, x);
+        const w = minW + (maxW - minW) * 0.5 * (1 + Math.cos(lobes * a));
+        const d = (Math.hypot(x, y) - r) / (w / 2);
+        return Math.exp(-d * d);
+    });
+}
+
+// ── Composition ───────────────────────────────────────────────────────────────
+
+/**
+ * Lay several density grids out in one grid, each shrunk (aspect preserved)
+ * and centred inside its own NDC region.  Each child's mass is scaled to its
+ * weight, so sampleFromDensity splits the atom budget between them by weight.
+ *
+ * @param {{ grid: Float32Array, region: number[], weight?: number }[]} children
+ *        region = [x, y, w, h] — bottom-left corner and size in NDC
+ * @returns {Float32Array}  normalised to [0, 1]
+ */
+export function compose(children) {
+    const G   = GRID_SIZE;
+    const out = new Float32Array(G * G);
+    const tmp = new Float32Array(G * G);
+
+    for (const { grid, region: [rx, ry, rw, rh], weight = 1 } of children) {
+        const s  = Math.min(rw, rh) / 2;   // child NDC [-1,1] → region
+        const cx = rx + rw / 2;
+        const cy = ry + rh / 2;
+
+        // Box-downsample: every source cell deposits into the cell it lands on
+        tmp.fill(0);
+        let mass = 0;
+        for (let row = 0; row < G; row++) {
+            for (let col = 0; col < G; col++) {
+                const v = grid[row * G + col];
+                if (v <= 0) continue;
+                const { x, y } = toNDC(col, row, G, G);
+                const c = Math.round(((cx + x * s + 1) / 2) * (G - 1));
+                const r = Math.round(((cy + y * s + 1) / 2) * (G - 1));
+                if (c < 0 || c >= G || r < 0 || r >= G) continue;
+                tmp[r * G + c] += v;
+                mass += v;
+            }
+        }
+        if (mass > 0) for (let i = 0; i < out.length; i++) out[i] += tmp[i] * weight / mass;
+    }
+
+    let mx = 0;
+    for (let i = 0; i < out.length; i++) if (out[i] > mx) mx = out[i];
+    if (mx > 0) for (let i = 0; i < out.length; i++) out[i] /= mx;
+    return out;
+}
@@ -7,20 +7,25 @@
 
 import {
     circle, ring, star, diamond, spiral, heart, wave, hexGrid,
-    triangle, cross, eiffelTower,
-    GRID_SIZE,
+    triangle, cross, eiffelTower, dotGrid,
+    gaussianBlob, swellRing,
+    compose, GRID_SIZE,
 } from './primitives.js';
 
 import {
     lissajous, lorenz, interference, julia, dragon, rabbit,
     rossler, rose, logSpiral, mandelbrot,
+    phyllotaxis, sphere, helix3D,
 } from './mathematical.js';
 
 import {
     dna, nanotube, crystal, graphene2D,
 } from './molecular.js';
 
+import { text } from './text.js';
+
 import { N } from '../gpu/buffers.js';
+import { random } from '../random.js';
 
 // ── Shape registry ────────────────────────────────────────────────────────────
 // Each key → unique visual.  No two entries share a generator + params combo.
@@ -39,22 +44,34 @@ const REGISTRY = {
     heart:        () => heart(),
     wave:         () => wave(),
     hexgrid:      () => hexGrid(),
+    grid:         () => dotGrid(),
     eiffeltower:  () => eiffelTower(),
 
+    // ── Density functions: atoms spread by a smooth field ────────────────────
+    gaussian:     () => gaussianBlob(),                  // soft cloud
+    crescent:     () => swellRing(),                     // thick → thin ring
+    swellring:    () => swellRing(0.62, 0.04, 0.22, 3),  // three swellings
+
     // ── Tier 2: mathematical ──────────────────────────────────────────────────
     lissajous:    () => lissajous(3, 2, Math.PI / 4),   // pretzel
     pretzel:      () => lissajous(5, 4, Math.PI / 6),   // denser pretzel
     trefoil:      () => lissajous(3, 1, Math.PI / 2),   // 3-lobed curve
+    figure8:      () => lissajous(1, 2, 0),             // 1:2 figure-eight
     rose:         () => rose(4),                         // 8-petal rose
-    rose3:        () => rose(3),                         // 6-petal rose
+    rose3:        () => rose(3),                         // 3-petal rose
+    rose5:        () => rose(5),                         // 5-petal rose
     lorenz:       () => lorenz(),                        // double-wing butterfly
     rossler:      () => rossler(),                       // single-scroll spiral
     interference: () => interference(),                  // wave fringes
     galaxy:       () => logSpiral(),                     // logarithmic spiral arms
+    galaxy2:      () => logSpiral(0.18, 3.5, 0.055, 2, true),  // two-arm, clockwise
+    phyllotaxis:  () => phyllotaxis(),                   // golden-angle seed head
     julia:        () => julia(-0.7, 0.27),               // dendrite / lightning
     dragon:       () => dragon(),                        // archipelago islands
     rabbit:       () => rabbit(),                        // Douady's 3-lobed rabbit
     mandelbrot:   () => mandelbrot(),                    // classic cardioid
+    sphere:       () => sphere(),                        // 3D ball, depth-shaded
+    helix3d:      () => helix3D(),                       // coil wrapping in depth
 
     // ── Tier 3: molecular / structural ────────────────────────────────────────
     dna:          () => dna(),
@@ -77,6 +94,8 @@ const ALIASES = {
     paris:          'eiffeltower',
 
     // mathematical
+    flower:         'rose',
+    petals:         'rose',
     butterfly:      'lorenz',
     attractor:      'lorenz',
     chaos:          'rossler',
@@ -84,8 +103,19 @@ const ALIASES = {
     fringes:        'interference',
     diffraction:    'interference',
     waves:          'interference',
+    spiralgalaxy:   'galaxy2',
     fractal:        'julia',
     lightning:      'julia',
+    infinity:       'figure8',
+    eight:          'figure8',
+    lemniscate:     'figure8',
+    sunflower:      'phyllotaxis',
+    seeds:          'phyllotaxis',
+    globe:          'sphere',
+    ball:           'sphere',
+    orb:            'sphere',
+    coil:           'helix3d',
+    spring:         'helix3d',
 
     // molecular
     doublehelix:    'dna',
@@ -102,6 +132,58 @@ const ALIASES = {
 /** All registered shape names (canonical, no aliases). */
 export const SHAPE_NAMES = Object.keys(REGISTRY);
 
+// Free text is spelled out rather than looked up: `"hello"` or `text: hello`
+// resolves to the canonical key `text:HELLO`.
+const TEXT_PREFIX = 'text:';
+const TEXT_INPUT  = /^(?:["'\u201c](.+)["'\u201d]|text:\s*(.+))$/is;
+
+// Dropped images are registered at runtime under `image:<file name>`.
+const IMAGE_PREFIX = 'image:';
+
+// Explicit grid size: `grid 2x8` → `grid:2x8` (rows × cols, square cells).
+const GRID_PREFIX = 'grid:';
+const GRID_INPUT  = /^grid\s*(\d+)\s*[x×*]\s*(\d+)$/i;
+const MAX_GRID    = 32;
+
+// Density functions with parameters: `gaussian 0.15` (σ), `crescent 0.3`
+// (thickest width) → `gaussian:0.15`, `crescent:0.3`.
+const FIELD_INPUT  = /^(gaussian|crescent)\s+(\d*\.?\d+)$/i;
+const FIELD_KEY    = /^(gaussian|crescent):(\d*\.?\d+)$/;
+const FIELD_RANGE  = { gaussian: [0.05, 1.0], crescent: [0.05, 0.6] };
+const FIELDS       = {
+    gaussian: v => gaussianBlob(v),
+    crescent: v => swellRing(0.6, 0.03, v),
+};
+
+// Lorenz parameters: `lorenz 10 28 2.667` (σ ρ β) → `lorenz:10,28,2.667`.
+// Ranges keep the fixed-step integration finite.
+const LORENZ_INPUT = /^lorenz\s+(\d*\.?\d+)\s+(\d*\.?\d+)\s+(\d*\.?\d+)$/i;
+const LORENZ_KEY   = /^lorenz:(\d*\.?\d+),(\d*\.?\d+),(\d*\.?\d+)$/;
+const LORENZ_RANGE = [[1, 30], [1, 60], [0.5, 6]];   // σ, ρ, β
+
+// Two shapes in one scene: `sun above mountains`, `heart beside star`.
+// Canonical keys only use `above` / `beside`; `below` / `and` are rewritten.
+// Text keys are upper-case, so a spelled-out "A ABOVE B" never matches here.
+const GROUP_INPUT = /^(.+?)\s+(above|below|beside|and)\s+(.+)$/i;
+const GROUP_KEY   = /^(.+?) (above|beside) (.+)$/;
+const GROUP_REGIONS = {
+    above:  [[-1,  0, 2, 1], [-1, -1, 2, 1]],   // first on top, second beneath
+    beside: [[-1, -1, 1, 2], [ 0, -1, 1, 2]],   // first on the left
+};
+
+// Shape-space blend: `40% circle, 60% spiral` → `40% circle + 60% spiral`.
+// Weights are normalised to sum to 100.  A blend key has no density grid of
+// its own — main.js builds both target sets and lerps them per atom.
+const BLEND_INPUT = /^(\d{1,3})\s*%\s*(.+?)\s*(?:,|\s)\s*(?:and\s+)?(\d{1,3})\s*%\s*(.+)$/i;
+const BLEND_KEY   = /^(\d+)% (.+?) \+ (\d+)% (.+)$/;
+
+// Animated shapes: `spinning spiral`, `travelling wave`.  The motion word is
+// read from the prompt; the shape itself resolves as usual.
+const SPIN_INPUT  = /\b(spinning|rotating|turning|whirling)\b/i;
+const DRIFT_INPUT = /\b(travell?ing|moving|scrolling|drifting)\b/i;
+const SPIN_SPEED  = 0.5;    // rad/s, anticlockwise
+const DRIFT_SPEED = 0.15;   // NDC/s, rightward
+
 // Cache: name → Float32Array(GRID_SIZE²)
 const _cache = new Map();
 
@@ -111,6 +193,44 @@ const _cache = new Map();
  * @returns {Float32Array}  GRID_SIZE × GRID_SIZE, values in [0, 1]
  */
 export function getShape(name) {
+    if (BLEND_KEY.test(name)) throw new Error(`"${name}" is a blend — use blendParts()`);
+    if (name.startsWith(IMAGE_PREFIX)) {
+        if (!_cache.has(name)) throw new Error(`Unknown image: "${name}"`);
+        return _cache.get(name);
+    }
+    const group = name.match(GROUP_KEY);
+    if (group) {
+        if (!_cache.has(name)) {
+            const [, a, rel, b] = group;
+            const [ra, rb] = GROUP_REGIONS[rel];
+            _cache.set(name, compose([
+                { grid: getShape(a), region: ra },
+                { grid: getShape(b), region: rb },
+            ]));
+        }
+        return _cache.get(name);
+    }
+    if (name.startsWith(TEXT_PREFIX)) {
+        if (!_cache.has(name)) _cache.set(name, text(name.slice(TEXT_PREFIX.length)));
+        return _cache.get(name);
+    }
+    const field = name.match(FIELD_KEY);
+    if (field) {
+        if (!_cache.has(name)) _cache.set(name, FIELDS[field[1]](Number(field[2])));
+        return _cache.get(name);
+    }
+    const attractor = name.match(LORENZ_KEY);
+    if (attractor) {
+        if (!_cache.has(name)) _cache.set(name, lorenz(...attractor.slice(1).map(Number)));
+        return _cache.get(name);
+    }
+    if (name.startsWith(GRID_PREFIX)) {
+        if (!_cache.has(name)) {
+            const [rows, cols] = name.slice(GRID_PREFIX.length).split('x').map(Number);
+            _cache.set(name, dotGrid(rows, cols));
+        }
+        return _cache.get(name);
+    }
     const key = _resolve(name);
     if (!_cache.has(key)) {
         if (!REGISTRY[key]) throw new Error(`Unknown shape: "${name}"`);
@@ -119,16 +239,134 @@ export function getShape(name) {
     return _cache.get(key);
 }
 
+/**
+ * Register a density grid built from an image (see image.js) and return the
+ * key that resolveShape / getShape accept for it.
+ */
+export function defineImage(name, grid) {
+    const key = IMAGE_PREFIX + name;
+    _cache.set(key, grid);
+    return key;
+}
+
+/**
+ * Split a blend key into its two shapes and the weight `t` of the second
+ * (0 = all `a`, 1 = all `b`).  Returns null for anything that isn't a blend.
+ * @returns {{ a: string, b: string, t: number } | null}
+ */
+export function blendParts(key) {
+    const m = key.match(BLEND_KEY);
+    if (!m) return null;
+    const [, wa, a, wb, b] = m;
+    return { a, b, t: Number(wb) / (Number(wa) + Number(wb)) };
+}
+
+/**
+ * Motion asked for in a prompt: `spin` (rad/s) when it says spinning /
+ * rotating, `drift` (NDC/s) when it says travelling / moving.  Both are 0 for
+ * a still shape, for quoted text (spelled out verbatim) and for dropped
+ * images, whose file names are not prompts.
+ * @returns {{ spin: number, drift: number }}
+ */
+export function motionFor(input) {
+    if (input.startsWith(IMAGE_PREFIX) || TEXT_INPUT.test(input.trim())) return { spin: 0, drift: 0 };
+    return {
+        spin:  SPIN_INPUT.test(input)  ? SPIN_SPEED  : 0,
+        drift: DRIFT_INPUT.test(input) ? DRIFT_SPEED : 0,
+    };
+}
+
 /**
  * Resolve a user-typed string to a canonical registry key.
+ * Quoted input (or `text: …`) resolves to a `text:` key that spells it out.
+ * `grid RxC` resolves to a `grid:` key with that many rows and columns.
+ * `gaussian 0.2` / `crescent 0.3` resolve to a parameterised density field.
+ * `lorenz σ ρ β` resolves to a `lorenz:` key with clamped parameters.
+ * `A above B` / `A beside B` resolve each side and compose them.
+ * `40% A, 60% B` resolves each side into a blend key (see blendParts).
+ * Sentences ("show me a spiral galaxy") fall back to the first shape word.
  * Falls back to 'circle' if nothing matches.
  */
 export function resolveShape(input) {
+    return _lookup(input, true) ?? 'circle';
+}
+
+/**
+ * resolveShape without the 'circle' fallback: null when nothing matches.
+ * Prefix matches ("hear" → heart) are only tried when `partial` is set, so a
+ * composition side has to name a shape, alias or keyword outright.
+ */
+function _lookup(input, partial) {
+    if (input.startsWith(IMAGE_PREFIX) && _cache.has(input)) return input;
+
+    const quoted = input.trim().match(TEXT_INPUT);
+    if (quoted) {
+        const words = (quoted[1] ?? quoted[2]).trim().replace(/\s+/g, ' ');
+        return TEXT_PREFIX + words.toUpperCase();
+    }
+
+    const sized = input.trim().match(GRID_INPUT);
+    if (sized) {
+        const clamp = n => Math.min(Math.max(parseInt(n, 10), 1), MAX_GRID);
+        return `${GRID_PREFIX}${clamp(sized[1])}x${clamp(sized[2])}`;
+    }
+
+    const field = input.trim().match(FIELD_INPUT);
+    if (field) {
+        const kind = field[1].toLowerCase();
+        const [lo, hi] = FIELD_RANGE[kind];
+        const v = Math.min(Math.max(Number(field[2]), lo), hi);
+        return `${kind}:${+v.toFixed(3)}`;
+    }
+
+    const attractor = input.trim().match(LORENZ_INPUT);
+    if (attractor) {
+        const params = attractor.slice(1).map((v, i) => {
+            const [lo, hi] = LORENZ_RANGE[i];
+            return +Math.min(Math.max(Number(v), lo), hi).toFixed(3);
+        });
+        return `lorenz:${params.join(',')}`;
+    }
+
+    const blend = input.trim().match(BLEND_INPUT);
+    if (blend) {
+        const [, wa, a, wb, b] = blend;
+        const sum = Number(wa) + Number(wb);
+        const pb  = sum > 0 ? Math.round(100 * Number(wb) / sum) : 50;
+        return `${100 - pb}% ${resolveShape(a)} + ${pb}% ${resolveShape(b)}`;
+    }
+
+    const group = input.trim().match(GROUP_INPUT);
+    if (group) {
+        const [, a, rel, b] = group;
+        const [first, second] = rel.toLowerCase() === 'below' ? [b, a] : [a, b];
+        const how = /^(above|below)$/i.test(rel) ? 'above' : 'beside';
+        // Only compose real shapes: "rock and roll" is a sentence, not a scene
+        const ka = _lookup(first, false), kb = _lookup(second, false);
+        if (ka && kb) return `${ka} ${how} ${kb}`;
+    }
+
     const k = input.toLowerCase().trim().replace(/\s+/g, '');
     if (REGISTRY[k])  return k;
     if (ALIASES[k])   return ALIASES[k];
-    const partial = SHAPE_NAMES.find(n => n.startsWith(k));
-    return partial ?? 'circle';
+    const prefix = partial ? SHAPE_NAMES.find(n => n.startsWith(k)) : null;
+    return prefix ?? _keyword(input);
+}
+
+/**
+ * First registry name or alias mentioned in free text.  Adjacent word pairs
+ * are tried before single words so "spiral galaxy" beats "spiral".
+ */
+function _keyword(input) {
+    const words = input.toLowerCase().match(/[a-z0-9]+/g) ?? [];
+    for (const n of [2, 1]) {
+        for (let i = 0; i + n <= words.length; i++) {
+            const k = words.slice(i, i + n).join('');
+            if (REGISTRY[k]) return k;
+            if (ALIASES[k])  return ALIASES[k];
+        }
+    }
+    return null;
 }
 
 function _resolve(name) {
@@ -157,8 +395,8 @@ export function sampleFromDensity(densityGrid) {
     if (total === 0) {
         const out = new Float32Array(N * 2);
         for (let i = 0; i < N; i++) {
-            out[i * 2    ] = (Math.random() * 2 - 1) * 0.85;
-            out[i * 2 + 1] = (Math.random() * 2 - 1) * 0.85;
+            out[i * 2    ] = (random() * 2 - 1) * 0.85;
+            out[i * 2 + 1] = (random() * 2 - 1) * 0.85;
         }
         return out;
     }
@@ -173,7 +411,7 @@ export function sampleFromDensity(densityGrid) {
     const out = new Float32Array(N * 2);
     for (let i = 0; i < N; i++) {
         let lo = 0, hi = cdf.length - 1;
-        const r = Math.random();
+        const r = random();
         while (lo < hi) {
             const mid = (lo + hi) >> 1;
             if (cdf[mid] < r) lo = mid + 1;
@@ -181,8 +419,47 @@ export function sampleFromDensity(densityGrid) {
         }
         const row = Math.floor(lo / W);
         const col = lo % W;
-        out[i * 2    ] = ((col + Math.random()) / W) * 2 - 1;
-        out[i * 2 + 1] = ((row + Math.random()) / H) * 2 - 1;
+        out[i * 2    ] = ((col + random()) / W) * 2 - 1;
+        out[i * 2 + 1] = ((row + random()) / H) * 2 - 1;
     }
     return out;
 }
+
+/**
+ * Squeeze sampled positions so a shape keeps its proportions on a canvas of
+ * the given width / height ratio.  NDC spans the whole canvas, so on a wide
+ * window x is scaled by 1/aspect and on a tall one y by aspect; the shape
+ * stays centred and inside the screen.  Modifies `positions` in place.
+ *
+ * @param {Float32Array} positions   N × 2 interleaved NDC positions
+ * @param {number}       aspect      canvas width / height
+ * @returns {Float32Array}           the same array
+ */
+export function fitToAspect(positions, aspect) {
+    const sx = aspect > 1 ? 1 / aspect : 1;
+    const sy = aspect < 1 ? aspect     : 1;
+    for (let i = 0; i < positions.length; i += 2) {
+        positions[i    ] *= sx;
+        positions[i + 1] *= sy;
+    }
+    return positions;
+}
+
+/**
+ * Quantise positions onto a lattice for a blocky, pixel-art look.  The cell
+ * is `step` of each NDC axis (NDC spans 2), counted from the bottom-left
+ * corner — so it is `step` of the width by `step` of the height, square only
+ * on a square window.  Many atoms share each lattice point, so
+ * coarse steps read as a chunky mosaic.  Modifies `positions` in place.
+ *
+ * @param {Float32Array} positions   N × 2 interleaved NDC positions
+ * @param {number}       step        cell size, fraction of the screen (> 0)
+ * @returns {Float32Array}           the same array
+ */
+export function snapToGrid(positions, step) {
+    const cell = 2 * step;
+    for (let i = 0; i < positions.length; i++) {
+        positions[i] = Math.round((positions[i] + 1) / cell) * cell - 1;
+    }
+    return positions;
+}
@@ -0,0 +1,163 @@
+/**
+ * text.js — Words spelled in atoms.
+ *
+ * Renders a string with a bundled 5×7 bitmap font into a density grid
+ * (Float32Array(GRID_SIZE²), values in [0,1], row 0 = NDC y = -1).
+ *
+ * Layout
+ * ──────
+ * Words are greedily wrapped onto as many lines as gives the largest glyph
+ * size, each line is centred horizontally, and the block is centred
+ * vertically.  Long strings therefore drop to smaller glyphs on more lines
+ * instead of running off-screen.  The importance sampler spends the atom
+ * budget in proportion to lit area, so every glyph gets its share.
+ */
+
+import { GRID_SIZE, gaussianBlur } from './primitives.js';
+
+const G = GRID_SIZE;
+
+const GLYPH_W  = 5;
+const GLYPH_H  = 7;
+const CELL_W   = GLYPH_W + 1;   // 1 font pixel of letter spacing
+const CELL_H   = GLYPH_H + 2;   // 2 font pixels of line spacing
+const EXTENT   = 0.88;          // usable half-width / half-height in NDC
+const MAX_PX   = 0.11;          // largest font pixel (NDC) — caps single glyphs
+const MAX_CHARS = 64;
+
+// 5×7 font, rows top → bottom, '1' = lit.  Unknown characters render blank.
+const FONT = {
+    'A': '01110 10001 10001 11111 10001 10001 10001',
+    'B': '11110 10001 10001 11110 10001 10001 11110',
+    'C': '01110 10001 10000 10000 10000 10001 01110',
+    'D': '11110 10001 10001 10001 10001 10001 11110',
+    'E': '11111 10000 10000 11110 10000 10000 11111',
+    'F': '11111 10000 10000 11110 10000 10000 10000',
+    'G': '01110 10001 10000 10111 10001 10001 01111',
+    'H': '10001 10001 10001 11111 10001 10001 10001',
+    'I': '01110 00100 00100 00100 00100 00100 01110',
+    'J': '00111 00010 00010 00010 00010 10010 01100',
+    'K': '10001 10010 10100 11000 10100 10010 10001',
+    'L': '10000 10000 10000 10000 10000 10000 11111',
+    'M': '10001 11011 10101 10101 10001 10001 10001',
+    'N': '10001 10001 11001 10101 10011 10001 10001',
+    'O': '01110 10001 10001 10001 10001 10001 01110',
+    'P': '11110 10001 10001 11110 10000 10000 10000',
+    'Q': '01110 10001 10001 10001 10101 10010 01101',
+    'R': '11110 10001 10001 11110 10100 10010 10001',
+    'S': '01111 10000 10000 01110 00001 00001 11110',
+    'T': '11111 00100 00100 00100 00100 00100 00100',
+    'U': '10001 10001 10001 10001 10001 10001 01110',
+    'V': '10001 10001 10001 10001 10001 01010 00100',
+    'W': '10001 10001 10001 10101 10101 10101 01010',
+    'X': '10001 10001 01010 00100 01010 10001 10001',
+    'Y': '10001 10001 01010 00100 00100 00100 00100',
+    'Z': '11111 00001 00010 00100 01000 10000 11111',
+    '0': '01110 10001 10011 10101 11001 10001 01110',
+    '1': '00100 01100 00100 00100 00100 00100 01110',
+    '2': '01110 10001 00001 00010 00100 01000 11111',
+    '3': '11111 00010 00100 00010 00001 10001 01110',
+    '4': '00010 00110 01010 10010 11111 00010 00010',
+    '5': '11111 10000 11110 00001 00001 10001 01110',
+    '6': '00110 01000 10000 11110 10001 10001 01110',
+    '7': '11111 00001 00010 00100 01000 01000 01000',
+    '8': '01110 10001 10001 01110 10001 10001 01110',
+    '9': '01110 10001 10001 01111 00001 00010 01100',
+    '!': '00100 00100 00100 00100 00100 00000 00100',
+    '?': '01110 10001 00001 00010 00100 00000 00100',
+    '.': '00000 00000 00000 00000 00000 01100 01100',
+    ',': '00000 00000 00000 00000 01100 00100 01000',
+    ':': '00000 01100 01100 00000 01100 01100 00000',
+    '-': '00000 00000 00000 11111 00000 00000 00000',
+    '+': '00000 00100 00100 11111 00100 00100 00000',
+};
+
+// Decode once: char → Uint8Array(GLYPH_W × GLYPH_H), row-major, top row first
+const GLYPHS = new Map(Object.entries(FONT).map(([ch, rows]) =>
+    [ch, Uint8Array.from(rows.replace(/ /g, ''), c => c === '1' ? 1 : 0)]));
+
+
+// ── Layout helpers ────────────────────────────────────────────────────────────
+
+/** Greedy word wrap: lines no longer than `width` chars (long words overflow). */
+function wrap(words, width) {
+    const lines = [];
+    let line = '';
+    for (const w of words) {
+        if (line && line.length + 1 + w.length > width) {
+            lines.push(line);
+            line = w;
+        } else {
+            line = line ? `${line} ${w}` : w;
+        }
+    }
+    if (line) lines.push(line);
+    return lines;
+}
+
+/** Font pixel size (NDC) that fits `lines` inside the usable extent. */
+function pixelSize(lines) {
+    const cols = Math.max(...lines.map(l => l.length)) * CELL_W - 1;
+    const rows = lines.length * CELL_H - (CELL_H - GLYPH_H);
+    return Math.min((2 * EXTENT) / cols, (2 * EXTENT) / rows, MAX_PX);
+}
+
+/** Try every wrap width and keep the one giving the biggest glyphs. */
+function bestLayout(str) {
+    const words = str.split(' ');
+    const minW  = Math.max(...words.map(w => w.length));
+    let best = null;
+    for (let width = minW; width <= str.length; width++) {
+        const lines = wrap(words, width);
+        const px    = pixelSize(lines);
+        if (!best || px > best.px) best = { lines, px };
+    }
+    return best;
+}
+
+
+// ── Shape generator ───────────────────────────────────────────────────────────
+
+/**
+ * Spell `str` in a 5×7 bitmap font.  Case-insensitive; characters outside
+ * the font render as gaps.  Truncated to MAX_CHARS.  Throws when nothing
+ * would be lit (blank input, or only characters outside the font), so the
+ * caller reports it instead of morphing to a random scatter.
+ */
+export function text(str) {
+    const clean = str.toUpperCase().replace(/\s+/g, ' ').trim().slice(0, MAX_CHARS);
+    if (![...clean].some(c => GLYPHS.get(c)?.some(Boolean))) {
+        throw new Error(clean ? `nothing to spell in "${clean}"` : 'nothing to spell');
+    }
+    const raw = new Float32Array(G * G);
+
+    const { lines, px } = bestLayout(clean);
+    const blockH = (lines.length * CELL_H - (CELL_H - GLYPH_H)) * px;
+    const top    = blockH / 2;
+
+    for (let row = 0; row < G; row++) {
+        const y  = (row / (G - 1)) * 2 - 1;
+        const fy = (top - y) / px;                        // font px below block top
+        if (fy < 0) continue;
+        const li = Math.floor(fy / CELL_H);
+        const gy = Math.floor(fy) - li * CELL_H;          // row within glyph cell
+        if (li >= lines.length || gy >= GLYPH_H) continue;
+
+        const line = lines[li];
+        const left = -((line.length * CELL_W - 1) * px) / 2;
+
+        for (let col = 0; col < G; col++) {
+            const x  = (col / (G - 1)) * 2 - 1;
+            const fx = (x - left) / px;
+            if (fx < 0) continue;
+            const ci = Math.floor(fx / CELL_W);
+            const gx = Math.floor(fx) - ci * CELL_W;
+            if (ci >= line.length || gx >= GLYPH_W) continue;
+
+            const glyph = GLYPHS.get(line[ci]);
+            if (glyph && glyph[gy * GLYPH_W + gx]) raw[row * G + col] = 1;
+        }
+    }
+
+    return gaussianBlur(raw, G, G, 0.8);
+}
@@ -0,0 +1,69 @@
+/**
+ * camera.js — Canvas wheel / middle-drag → view zoom and pan.
+ *
+ * Single responsibility: track a 2D camera over the NDC world the render pass
+ * samples from.  A screen point s (NDC) shows world point  s / zoom + (x, y).
+ *
+ *   wheel          → zoom about the cursor
+ *   middle-drag    → pan
+ *
+ * Zoom never goes below 1 and the view is kept inside the world square, so
+ * the render pass never samples outside the density grid.
+ */
+
+import { eventToNdc, ndcToWorld } from './coords.js';
+
+export const MIN_ZOOM = 1.0;
+export const MAX_ZOOM = 16.0;
+
+/**
+ * @param {HTMLCanvasElement} canvas
+ * @returns {{ x: number, y: number, zoom: number, reset: () => void }}
+ *          live state, mutated by events
+ */
+export function initCamera(canvas) {
+    const state = { x: 0, y: 0, zoom: 1, reset };
+    let drag    = null;   // last pointer position (NDC) while middle-dragging
+
+    function reset() {
+        state.x = state.y = 0;
+        state.zoom = 1;
+    }
+
+    function clampView() {
+        const room = 1 - 1 / state.zoom;
+        state.x = Math.min(Math.max(state.x, -room), room);
+        state.y = Math.min(Math.max(state.y, -room), room);
+    }
+
+    canvas.addEventListener('wheel', e => {
+        e.preventDefault();
+        const s    = eventToNdc(e, canvas);
+        const w    = ndcToWorld(s, state);         // world point under the cursor
+        state.zoom = Math.min(Math.max(state.zoom * Math.exp(-e.deltaY * 0.0015), MIN_ZOOM), MAX_ZOOM);
+        state.x    = w.x - s.x / state.zoom;       // …stays under the cursor
+        state.y    = w.y - s.y / state.zoom;
+        clampView();
+    }, { passive: false });
+
+    canvas.addEventListener('pointerdown', e => {
+        if (e.button !== 1) return;
+        e.preventDefault();
+        canvas.setPointerCapture(e.pointerId);
+        drag = eventToNdc(e, canvas);
+    });
+    canvas.addEventListener('pointermove', e => {
+        if (!drag) return;
+        const s = eventToNdc(e, canvas);
+        state.x -= (s.x - drag.x) / state.zoom;
+        state.y -= (s.y - drag.y) / state.zoom;
+        drag = s;
+        clampView();
+    });
+
+    const release = e => { if (e.button === 1 || e.type === 'pointercancel') drag = null; };
+    canvas.addEventListener('pointerup',     release);
+    canvas.addEventListener('pointercancel', release);
+
+    return state;
+}
@@ -0,0 +1,71 @@
+/**
+ * capture.js — Canvas → downloadable files.
+ *
+ * Single responsibility: save what the WebGPU canvas is showing.  The canvas
+ * texture is only readable until the frame is presented, so callers must
+ * capture in the same task that submitted the frame.
+ */
+
+/** Timestamped file name, e.g. `tofu-2026-10-16T14-03-22-123Z.png`. */
+function stamp(ext) {
+    return `tofu-${new Date().toISOString().replace(/[:.]/g, '-')}.${ext}`;
+}
+
+/** Hand a blob to the browser as a download. */
+function download(blob, name) {
+    const url = URL.createObjectURL(blob);
+    const a   = document.createElement('a');
+    a.href     = url;
+    a.download = name;
+    a.click();
+    URL.revokeObjectURL(url);
+}
+
+/**
+ * Save the current frame as a PNG.  Call right after queue.submit().
+ * @param {HTMLCanvasElement} canvas
+ */
+export function saveFrame(canvas) {
+    canvas.toBlob(blob => {
+        if (blob) download(blob, stamp('png'));
+        else      console.warn('[capture] canvas produced no image');
+    }, 'image/png');
+}
+
+/**
+ * Record the canvas to a WebM clip, downloaded when stopped.  Frames are
+ * pushed explicitly with `frame(nowMs)` right after each submit; the recorder
+ * stamps them with wall-clock time, so the caller must advance the simulation
+ * by real elapsed time.  On displays faster than `fps`, frames arriving less
+ * than `1 / fps` after the last pushed one are dropped.
+ *
+ * @param {HTMLCanvasElement} canvas
+ * @param {number}            fps  maximum frame rate of the clip
+ * @returns {{ fps: number, frame: (nowMs: number) => void, stop: () => void }}
+ */
+export function startRecording(canvas, fps = 60) {
+    const stream = canvas.captureStream(0);   // 0 → only on requestFrame()
+    const track  = stream.getVideoTracks()[0];
+    const type   = ['video/webm;codecs=vp9', 'video/webm']
+        .find(t => MediaRecorder.isTypeSupported(t));
+
+    const recorder = new MediaRecorder(stream, { mimeType: type, videoBitsPerSecond: 16e6 });
+    const chunks   = [];
+    recorder.ondataavailable = e => { if (e.data.size) chunks.push(e.data); };
+    recorder.onstop = () => download(new Blob(chunks, { type: recorder.mimeType }), stamp('webm'));
+    recorder.start();
+
+    const interval = 1000 / fps;
+    let   lastMs   = -Infinity;
+
+    return {
+        fps,
+        frame(nowMs) {
+            // Small slack so a 60 Hz display isn't halved by rAF jitter
+            if (nowMs - lastMs < interval * 0.9) return;
+            lastMs = nowMs;
+            track.requestFrame();
+        },
+        stop()  { recorder.stop(); track.stop(); },
+    };
+}
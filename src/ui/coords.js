@@ -0,0 +1,42 @@
+/**
+ * coords.js — Screen ↔ NDC ↔ world conversions, in one place.
+ *
+ * Conventions used everywhere in the app:
+ *
+ *   screen  CSS pixels inside the canvas — origin top-left, y DOWN
+ *   NDC     [-1, 1]² across the canvas  — origin centre,   y UP
+ *   world   NDC seen through the camera — world = ndc / zoom + (x, y)
+ *
+ * Shapes, atoms and the pointer force live in world space; the render pass
+ * maps world back to NDC with the same camera.
+ */
+
+/** Screen pixel (x, y) in a w × h canvas → NDC. */
+export function screenToNdc(x, y, w, h) {
+    return {
+        x: (x / w) * 2 - 1,
+        y: 1 - (y / h) * 2,   // NDC y is up
+    };
+}
+
+/** NDC → screen pixel in a w × h canvas.  Inverse of screenToNdc. */
+export function ndcToScreen(x, y, w, h) {
+    return {
+        x: ((x + 1) / 2) * w,
+        y: ((1 - y) / 2) * h,
+    };
+}
+
+/** NDC of a pointer / wheel event over `canvas`. */
+export function eventToNdc(e, canvas) {
+    const r = canvas.getBoundingClientRect();
+    return screenToNdc(e.clientX - r.left, e.clientY - r.top, r.width, r.height);
+}
+
+/** NDC → world through `camera` ({ x, y, zoom }, see camera.js). */
+export function ndcToWorld(p, camera) {
+    return {
+        x: p.x / camera.zoom + camera.x,
+        y: p.y / camera.zoom + camera.y,
+    };
+}
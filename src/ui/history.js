@@ -0,0 +1,60 @@
+/**
+ * history.js — Prompt history, persisted in localStorage.
+ *
+ * Single responsibility: remember what the user asked for, in order, and
+ * step back / forward through it.  Survives page reloads.  Navigation wraps
+ * at both ends; before the first step back the cursor sits on a draft slot
+ * one past the newest entry, from which stepping forward yields nothing.
+ */
+
+const STORAGE_KEY = 'tofu.history';
+const LIMIT       = 50;
+
+/**
+ * @returns {{ push: (s: string) => void, prev: () => string|null,
+ *             next: () => string|null, entries: string[] }}
+ */
+export function createHistory() {
+    const entries = load();
+    let   cursor  = entries.length;   // draft slot: one past the newest entry
+
+    function push(s) {
+        if (entries[entries.length - 1] !== s) {
+            entries.push(s);
+            if (entries.length > LIMIT) entries.shift();
+            save(entries);
+        }
+        cursor = entries.length;
+    }
+
+    function prev() {
+        if (!entries.length) return null;
+        cursor = (cursor - 1 + entries.length) % entries.length;
+        return entries[cursor];
+    }
+
+    function next() {
+        if (cursor >= entries.length) return null;   // on the draft slot
+        cursor = (cursor + 1) % entries.length;
+        return entries[cursor];
+    }
+
+    return { push, prev, next, entries };
+}
+
+function load() {
+    try {
+        const saved = JSON.parse(localStorage.getItem(STORAGE_KEY) ?? '[]');
+        return Array.isArray(saved) ? saved.filter(s => typeof s === 'string').slice(-LIMIT) : [];
+    } catch {
+        return [];   // corrupt entry or storage disabled
+    }
+}
+
+function save(entries) {
+    try {
+        localStorage.setItem(STORAGE_KEY, JSON.stringify(entries));
+    } catch {
+        // storage full / disabled — history just won't persist
+    }
+}
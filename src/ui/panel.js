@@ -14,24 +14,58 @@ import { N } from '../gpu/buffers.js';
 const fpsEl    = () => document.getElementById('fps');
 const statusEl = () => document.getElementById('status');
 const phaseEl  = () => document.getElementById('phase');
+const morphEl  = () => document.getElementById('morph');
+const colourEl = () => document.getElementById('colour');
 const responseEl = () => document.getElementById('response');
 
 // ── FPS counter ───────────────────────────────────────────────────────────────
 
-let _fpsCount = 0;
-let _fpsStart = performance.now();
+const FRAME_WINDOW = 120;                         // rolling average length (frames)
+const _frameMs = new Float32Array(FRAME_WINDOW);  // frame-to-frame interval
+const _cpuMs   = new Float32Array(FRAME_WINDOW);  // JS time spent in the frame
+let _frameIdx  = 0;
+let _frameN    = 0;
+let _lastMs    = 0;
+let _fpsStart  = performance.now();
+
+/** Mean of the first `n` (filled) slots of a rolling window; 0 when empty. */
+export function rollingMean(buf, n) {
+    let sum = 0;
+    for (let i = 0; i < n; i++) sum += buf[i];
+    return n ? sum / n : 0;
+}
+
+/**
+ * Call once per frame with the current timestamp (ms) and, optionally, how
+ * long the frame's JS work took.  The HUD refreshes once a second with the
+ * rolling averages over the last FRAME_WINDOW frames.
+ */
+export function tickFPS(nowMs, cpuMs = 0) {
+    if (_lastMs) {
+        _frameMs[_frameIdx] = nowMs - _lastMs;
+        _cpuMs[_frameIdx]   = cpuMs;
+        _frameIdx = (_frameIdx + 1) % FRAME_WINDOW;
+        _frameN   = Math.min(_frameN + 1, FRAME_WINDOW);
+    }
+    _lastMs = nowMs;
 
-/** Call once per frame with the current timestamp (ms). */
-export function tickFPS(nowMs) {
-    _fpsCount++;
-    if (nowMs - _fpsStart >= 1000) {
-        const fps = Math.round(_fpsCount * 1000 / (nowMs - _fpsStart));
-        fpsEl().textContent = `${fps} fps`;
-        _fpsCount = 0;
+    if (nowMs - _fpsStart >= 1000 && _frameN) {
+        const frame = rollingMean(_frameMs, _frameN);
+        const cpu   = rollingMean(_cpuMs,   _frameN);
+        fpsEl().textContent =
+            `${Math.round(1000 / frame)} fps · ${frame.toFixed(1)} ms · cpu ${cpu.toFixed(1)} ms`;
         _fpsStart = nowMs;
     }
 }
 
+/** Show / hide the render readout.  Returns true when it is now visible. */
+export function toggleFPS() {
+    const value = fpsEl().parentElement;
+    const label = value.previousElementSibling;
+    value.hidden = label.hidden = !value.hidden;
+    return !value.hidden;
+}
+
 // ── HUD setters ───────────────────────────────────────────────────────────────
 
 export function setStatus(label) {
@@ -42,17 +76,35 @@ export function setPhase(label) {
     phaseEl().textContent = label;
 }
 
+export function setMorph(label) {
+    morphEl().textContent = label;
+}
+
+export function setColour(label) {
+    colourEl().textContent = label;
+}
+
 // ── Input initialisation ──────────────────────────────────────────────────────
 
 /**
  * Wire up the prompt input panel.
  *
- * @param {{ onSubmit: (text: string) => void, onClear: () => void }} handlers
+ * Dropping an image file anywhere on the page hands it to `onImage`; a text
+ * file (a script of prompts) goes to `onScript`.
+ * ↑ / ↓ in a single-line prompt recall earlier entries from `history`.
+ *
+ * @param {{ onSubmit: (text: string) => void, onClear: () => void,
+ *           onImage: (file: File) => void, onScript: (file: File) => void,
+ *           history: { prev: () => string|null, next: () => string|null } }} handlers
  */
-export function initPanel({ onSubmit, onClear }) {
+export function initPanel({ onSubmit, onClear, onImage, onScript, history }) {
     const promptBox = document.getElementById('prompt-box');
     const sendBtn   = document.getElementById('send-btn');
 
+    // Atom count is chosen at load time (`?n=`), so fill it in here
+    document.getElementById('atoms').textContent =
+        N.toLocaleString('en-US').replace(/,/g, ' ');
+
     // Show available shape names as placeholder hint
     const hint = SHAPE_NAMES.slice(0, 5).join(', ') + '…';
     promptBox.placeholder = hint;
@@ -75,6 +127,14 @@ export function initPanel({ onSubmit, onClear }) {
             e.preventDefault();
             submit();
         }
+        // Up / Down recall history (only while the prompt is a single line)
+        if ((e.key === 'ArrowUp' || e.key === 'ArrowDown') && !promptBox.value.includes('\n')) {
+            const recalled = e.key === 'ArrowUp' ? history.prev() : history.next();
+            if (recalled !== null) {
+                e.preventDefault();
+                promptBox.value = recalled;
+            }
+        }
         // Escape clears and resumes auto-cycling
         if (e.key === 'Escape') {
             promptBox.value = '';
@@ -82,6 +142,61 @@ export function initPanel({ onSubmit, onClear }) {
             onClear();
         }
     });
+
+    window.addEventListener('dragover', e => e.preventDefault());
+    window.addEventListener('drop', e => {
+        e.preventDefault();
+        const files  = [...(e.dataTransfer?.files ?? [])];
+        const image  = files.find(f => f.type.startsWith('image/'));
+        const script = files.find(f => f.type === 'text/plain' || f.name.endsWith('.txt'));
+        if (image)       onImage(image);
+        else if (script) onScript(script);
+    });
+}
+
+/**
+ * Bind global single-key shortcuts.
+ * Ignored while the prompt box has focus so typing is never hijacked.
+ *
+ * @param {Record<string, () => void>} bindings  KeyboardEvent.code → handler
+ */
+export function initKeys(bindings) {
+    window.addEventListener('keydown', e => {
+        if (e.target instanceof HTMLTextAreaElement) return;
+        if (e.ctrlKey || e.metaKey || e.altKey)     return;
+        const handler = bindings[e.code];
+        if (handler) {
+            e.preventDefault();
+            handler(e);
+        }
+    });
+}
+
+// ── Toasts ────────────────────────────────────────────────────────────────────
+
+const TOAST_MS   = 4000;   // on screen before fading
+const TOAST_FADE = 400;    // matches the .toast opacity transition
+const MAX_TOASTS = 4;      // oldest is dropped beyond this
+
+/** Show a transient error over the canvas.  Several stack; each expires alone. */
+export function showToast(msg) {
+    const box = document.getElementById('toasts');
+    while (box.childElementCount >= MAX_TOASTS) box.firstElementChild.remove();
+
+    const el = document.createElement('div');
+    el.className   = 'toast';
+    el.textContent = msg;
+    box.appendChild(el);
+
+    setTimeout(() => {
+        el.classList.add('leaving');
+        setTimeout(() => el.remove(), TOAST_FADE);
+    }, TOAST_MS);
+}
+
+/** Replace the prompt box contents (e.g. when replaying history). */
+export function setPrompt(text) {
+    document.getElementById('prompt-box').value = text;
 }
 
 /** Show a message in the response area below the input. */
@@ -0,0 +1,47 @@
+/**
+ * pointer.js — Canvas pointer → physics force.
+ *
+ * Single responsibility: track the primary pointer over the canvas in NDC and
+ * expose a signed force strength the physics shader consumes each frame.
+ *
+ *   drag          → pull atoms toward the pointer   (strength > 0)
+ *   Shift + drag  → push atoms away from it         (strength < 0)
+ *
+ * The force is radial with a linear falloff to zero at POINTER_RADIUS.
+ */
+
+import { eventToNdc } from './coords.js';
+
+export const POINTER_RADIUS   = 0.25;   // NDC units
+export const POINTER_STRENGTH = 40.0;   // NDC/s² at the pointer centre
+
+/**
+ * @param {HTMLCanvasElement} canvas
+ * @returns {{ x: number, y: number, strength: number }}  live state, mutated by events
+ */
+export function initPointer(canvas) {
+    const state = { x: 0, y: 0, strength: 0 };
+
+    function track(e) {
+        const p = eventToNdc(e, canvas);
+        state.x = p.x;
+        state.y = p.y;
+        if (state.strength !== 0) {
+            state.strength = e.shiftKey ? -POINTER_STRENGTH : POINTER_STRENGTH;
+        }
+    }
+
+    canvas.addEventListener('pointerdown', e => {
+        if (e.button !== 0) return;
+        canvas.setPointerCapture(e.pointerId);
+        state.strength = POINTER_STRENGTH;
+        track(e);
+    });
+    canvas.addEventListener('pointermove', track);
+
+    const release = () => { state.strength = 0; };
+    canvas.addEventListener('pointerup',     release);
+    canvas.addEventListener('pointercancel', release);
+
+    return state;
+}
@@ -0,0 +1,39 @@
+/**
+ * script.js — Play a text file of prompts as a timed sequence.
+ *
+ * Single responsibility: turn a dropped `.txt` into prompts and step through
+ * them, one shape at a time, for hands-free demos and recordings.
+ *
+ *   # demo reel
+ *   circle
+ *   "hello"
+ *   spinning galaxy
+ *
+ * One prompt per line; blank lines and `#` comments are skipped.
+ */
+
+/** Non-blank, non-comment lines of `text`, trimmed, in order. */
+export function parseScript(text) {
+    return text.split(/\r?\n/)
+        .map(line => line.trim())
+        .filter(line => line && !line.startsWith('#'));
+}
+
+const sleep = ms => new Promise(resolve => setTimeout(resolve, ms));
+
+/**
+ * Show each prompt in turn.  `show` starts a transition and resolves once the
+ * shape is built; each shape is then held for `dwellMs()` before the next.
+ *
+ * @param {string[]}                     prompts
+ * @param {(prompt: string) => Promise}  show
+ * @param {() => number}                 dwellMs   read per prompt (morph length may change)
+ * @param {() => boolean}                stopped   true aborts before the next prompt
+ */
+export async function playScript(prompts, show, dwellMs, stopped) {
+    for (const prompt of prompts) {
+        if (stopped()) return;
+        await show(prompt);
+        await sleep(dwellMs());
+    }
+}
@@ -0,0 +1,46 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { createBenchmark } from '../src/benchmark.js';
+
+test('hands out shapes in order, then null', () => {
+    const b = createBenchmark(['circle', 'star'], 100);
+    assert.equal(b.next(), 'circle');
+    assert.equal(b.next(), 'star');
+    assert.equal(b.next(), null);
+    assert.equal(b.next(), null);
+});
+
+test('next is due only after dwelling once settled', () => {
+    const b = createBenchmark(['circle'], 100);
+    b.next();
+    assert.equal(b.frame(60, false), false);   // still morphing: no dwell
+    assert.equal(b.frame(60, false), false);
+    assert.equal(b.frame(50, true),  false);
+    assert.equal(b.frame(50, true),  true);
+});
+
+test('frames before next() are ignored', () => {
+    const b = createBenchmark(['circle'], 0);
+    assert.equal(b.frame(16, true), false);
+    assert.deepEqual(b.report(), []);
+});
+
+test('report has one row per finished shape', () => {
+    const b = createBenchmark(['circle', 'star'], 10);
+    b.next();
+    b.built(120);
+    b.frame(20, true);
+    b.frame(20, true);
+    b.next();
+    b.built(80);
+    b.frame(10, true);
+    b.next();
+
+    const rows = b.report();
+    assert.deepEqual(rows.map(r => r.shape),       ['circle', 'star']);
+    assert.deepEqual(rows.map(r => r['build ms']), [120, 80]);
+    assert.deepEqual(rows.map(r => r['frame ms']), [20, 10]);
+    assert.deepEqual(rows.map(r => r.fps),         [50, 100]);
+    assert.ok(rows.every(r => typeof r['settle ms'] === 'number'));
+});
@@ -0,0 +1,23 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { screenToNdc, ndcToScreen, ndcToWorld } from '../src/ui/coords.js';
+
+test('screen corners and centre map to NDC with y up', () => {
+    assert.deepEqual(screenToNdc(0,   0,   800, 600), { x: -1, y:  1 });
+    assert.deepEqual(screenToNdc(800, 600, 800, 600), { x:  1, y: -1 });
+    assert.deepEqual(screenToNdc(400, 300, 800, 600), { x:  0, y:  0 });
+});
+
+test('screen → NDC → screen round-trips', () => {
+    for (const [x, y] of [[0, 0], [123.5, 456.25], [799, 1], [400, 300]]) {
+        const n = screenToNdc(x, y, 800, 600);
+        const s = ndcToScreen(n.x, n.y, 800, 600);
+        assert.ok(Math.abs(s.x - x) < 1e-9 && Math.abs(s.y - y) < 1e-9, `${x},${y}`);
+    }
+});
+
+test('ndcToWorld undoes the camera zoom and offset', () => {
+    assert.deepEqual(ndcToWorld({ x: 0.5, y: -0.5 }, { x: 0, y: 0, zoom: 1 }), { x: 0.5, y: -0.5 });
+    assert.deepEqual(ndcToWorld({ x: 0.5, y: -0.5 }, { x: 1, y: 2, zoom: 2 }), { x: 1.25, y: 1.75 });
+});
@@ -0,0 +1,74 @@
+import { test, beforeEach } from 'node:test';
+import assert               from 'node:assert/strict';
+
+import { createHistory } from '../src/ui/history.js';
+
+// Minimal in-memory stand-in for the browser's localStorage
+const store = new Map();
+globalThis.localStorage = {
+    getItem:    k => store.get(k) ?? null,
+    setItem:    (k, v) => store.set(k, String(v)),
+    removeItem: k => store.delete(k),
+};
+
+beforeEach(() => store.clear());
+
+test('empty history recalls nothing', () => {
+    const h = createHistory();
+    assert.equal(h.prev(), null);
+    assert.equal(h.next(), null);
+});
+
+test('down from the draft slot returns null', () => {
+    const h = createHistory();
+    h.push('a');
+    h.push('b');
+    assert.equal(h.next(), null);
+    assert.equal(h.prev(), 'b');
+});
+
+test('up walks back from the newest entry and wraps', () => {
+    const h = createHistory();
+    for (const s of ['a', 'b', 'c']) h.push(s);
+    assert.deepEqual([h.prev(), h.prev(), h.prev(), h.prev()], ['c', 'b', 'a', 'c']);
+});
+
+test('down wraps from the newest entry to the oldest', () => {
+    const h = createHistory();
+    for (const s of ['a', 'b', 'c']) h.push(s);
+    h.prev();                                   // c
+    assert.equal(h.next(), 'a');
+    assert.equal(h.next(), 'b');
+});
+
+test('push resets the cursor to the draft slot', () => {
+    const h = createHistory();
+    h.push('a');
+    h.push('b');
+    h.prev();
+    h.prev();
+    h.push('c');
+    assert.equal(h.next(), null);
+    assert.equal(h.prev(), 'c');
+});
+
+test('repeated prompts are stored once', () => {
+    const h = createHistory();
+    h.push('a');
+    h.push('a');
+    assert.deepEqual(h.entries, ['a']);
+});
+
+test('entries persist across instances and are capped at 50', () => {
+    const h = createHistory();
+    for (let i = 0; i < 60; i++) h.push(`s${i}`);
+    const again = createHistory();
+    assert.equal(again.entries.length, 50);
+    assert.equal(again.entries[0], 's10');
+    assert.equal(again.prev(), 's59');
+});
+
+test('corrupt storage loads as empty', () => {
+    store.set('tofu.history', '{not json');
+    assert.deepEqual(createHistory().entries, []);
+});
@@ -0,0 +1,36 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { lorenz }                 from '../src/shapes/mathematical.js';
+import { getShape, resolveShape } from '../src/shapes/registry.js';
+
+const lit = grid => grid.reduce((n, v) => n + (v > 0.05), 0);
+
+/** Finite, in [0, 1], and lit over more than a dot. */
+function assertDrawn(grid, label, floor = 150) {
+    assert.ok(grid.every(v => Number.isFinite(v) && v >= 0 && v <= 1), `${label}: out of range`);
+    assert.ok(lit(grid) >= floor, `${label}: only ${lit(grid)} cells lit`);
+}
+
+test('default lorenz is the full butterfly', () => {
+    assertDrawn(getShape('lorenz'), 'lorenz', 3000);
+});
+
+test('every corner of the clamped parameter box draws a shape', () => {
+    // σ ∈ [1, 30], ρ ∈ [1, 60], β ∈ [0.5, 6]
+    for (const σ of [1, 30]) for (const ρ of [1, 60]) for (const β of [0.5, 6]) {
+        const key = resolveShape(`lorenz ${σ} ${ρ} ${β}`);
+        assert.equal(key, `lorenz:${σ},${ρ},${β}`);
+        assertDrawn(getShape(key), key);
+    }
+});
+
+test('a run that settles onto a fixed point draws its approach, not a dot', () => {
+    assertDrawn(getShape('lorenz:1,60,0.5'), 'lorenz:1,60,0.5', 300);
+    assertDrawn(getShape('lorenz:10,5,6'),   'lorenz:10,5,6',   300);
+});
+
+test('a diverging step size stops early and still returns a finite grid', () => {
+    const g = lorenz(10, 28, 8 / 3, 5);
+    assert.ok(g.every(v => Number.isFinite(v) && v >= 0 && v <= 1));
+});
@@ -0,0 +1,31 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+// tickFPS writes the HUD through document.getElementById; one stub element
+// stands in for all of them
+const hud = { textContent: '' };
+globalThis.document = { getElementById: () => hud };
+
+const { rollingMean, tickFPS } = await import('../src/ui/panel.js');
+
+test('rollingMean averages only the filled slots', () => {
+    const buf = new Float32Array([4, 8, 0, 0, 99]);   // 99: stale, not yet reached
+    assert.equal(rollingMean(buf, 2), 6);
+    assert.equal(rollingMean(buf, 5), 111 / 5);
+    assert.equal(rollingMean(buf, 0), 0);
+});
+
+test('tickFPS averages a partly filled window, then the last 120 frames', () => {
+    const t0 = performance.now() + 10_000;
+    tickFPS(t0, 1);                                   // first call only stamps the time
+
+    // One 10 ms interval in the window: the HUD refreshes on the next call
+    tickFPS(t0 + 10, 1);
+    assert.equal(hud.textContent, '100 fps · 10.0 ms · cpu 1.0 ms');
+
+    // 201 frames at 10 ms, then 50 at 20 ms: the window wraps and the refresh at
+    // t0 + 3010 sees 70 × 10 ms + 50 × 20 ms
+    for (let k = 2; k <= 201; k++) tickFPS(t0 + 10 * k, 1);
+    for (let m = 1; m <= 50; m++)  tickFPS(t0 + 2010 + 20 * m, 1);
+    assert.equal(hud.textContent, '71 fps · 14.2 ms · cpu 1.0 ms');
+});
@@ -0,0 +1,47 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+import { execFileSync } from 'node:child_process';
+
+import { mulberry32, SEED } from '../src/random.js';
+
+const draw = (rng, n) => Array.from({ length: n }, () => rng());
+
+test('mulberry32 repeats for the same seed', () => {
+    assert.deepEqual(draw(mulberry32(42), 100), draw(mulberry32(42), 100));
+});
+
+test('mulberry32 differs between seeds', () => {
+    assert.notDeepEqual(draw(mulberry32(1), 10), draw(mulberry32(2), 10));
+});
+
+test('mulberry32 stays in [0, 1)', () => {
+    for (const v of draw(mulberry32(7), 10_000)) assert.ok(v >= 0 && v < 1, String(v));
+});
+
+test('no URL means no seed', () => {
+    assert.equal(SEED, null);
+});
+
+// ── Seeded sampling ───────────────────────────────────────────────────────────
+// `?seed=` is read once at module load, so each run is a fresh node process
+// with `location` set before the app modules are imported.
+
+function sampleWith(search) {
+    const script = `
+        globalThis.location = { search: ${JSON.stringify(search)} };
+        const { sampleFromDensity } = await import(${JSON.stringify(new URL('../src/shapes/registry.js', import.meta.url).href)});
+        const { gaussianBlob }      = await import(${JSON.stringify(new URL('../src/shapes/primitives.js', import.meta.url).href)});
+        process.stdout.write(JSON.stringify([...sampleFromDensity(gaussianBlob(0.3))]));
+    `;
+    return JSON.parse(execFileSync(process.execPath, ['--input-type=module', '-e', script]));
+}
+
+test('sampleFromDensity repeats exactly for the same ?seed=', () => {
+    const a = sampleWith('?seed=7&n=4096');
+    assert.equal(a.length, 4096 * 2);
+    assert.deepEqual(sampleWith('?seed=7&n=4096'), a);
+});
+
+test('sampleFromDensity differs between seeds', () => {
+    assert.notDeepEqual(sampleWith('?seed=7&n=4096'), sampleWith('?seed=8&n=4096'));
+});
@@ -0,0 +1,126 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { resolveShape, getShape, blendParts, motionFor,
+         fitToAspect, snapToGrid } from '../src/shapes/registry.js';
+import { dotGrid, GRID_SIZE }  from '../src/shapes/primitives.js';
+
+// ── resolveShape ──────────────────────────────────────────────────────────────
+
+test('exact names, aliases and prefixes', () => {
+    assert.equal(resolveShape('circle'),    'circle');
+    assert.equal(resolveShape(' Spiral '),  'spiral');
+    assert.equal(resolveShape('butterfly'), 'lorenz');
+    assert.equal(resolveShape('hear'),      'heart');
+});
+
+test('free text falls back to a shape word, then circle', () => {
+    assert.equal(resolveShape('show me a heart please'), 'heart');
+    assert.equal(resolveShape('zzzz qqqq'),              'circle');
+});
+
+test('quoted and text: input is spelled out', () => {
+    assert.equal(resolveShape('"hello  world"'), 'text:HELLO WORLD');
+    assert.equal(resolveShape('text: hi'),       'text:HI');
+});
+
+test('sized grids are clamped', () => {
+    assert.equal(resolveShape('grid 2x8'),   'grid:2x8');
+    assert.equal(resolveShape('grid 0x100'), 'grid:1x32');
+});
+
+test('field and lorenz parameters are clamped', () => {
+    assert.equal(resolveShape('gaussian 0.15'),      'gaussian:0.15');
+    assert.equal(resolveShape('gaussian 9'),         'gaussian:1');
+    assert.equal(resolveShape('lorenz 10 28 2.667'), 'lorenz:10,28,2.667');
+    assert.equal(resolveShape('lorenz 0 500 9'),     'lorenz:1,60,6');
+});
+
+test('groups resolve each side; below swaps them', () => {
+    assert.equal(resolveShape('heart above star'), 'heart above star');
+    assert.equal(resolveShape('heart below star'), 'star above heart');
+    assert.equal(resolveShape('heart and star'),   'heart beside star');
+});
+
+test('sentences with a relation word but no shapes are not composed', () => {
+    assert.equal(resolveShape('rock and roll'),       'circle');
+    assert.equal(resolveShape('sun above mountains'), 'circle');
+    assert.equal(resolveShape('sun above heart'),     'heart');
+});
+
+test('compositions need both sides to name a shape', () => {
+    assert.equal(resolveShape('a big heart beside a star'), 'heart beside star');
+    assert.equal(resolveShape('"hi" above grid 2x3'),      'text:HI above grid:2x3');
+});
+
+test('blends normalise weights and split back apart', () => {
+    const key = resolveShape('1% circle, 3% spiral');
+    assert.equal(key, '25% circle + 75% spiral');
+    assert.deepEqual(blendParts(key), { a: 'circle', b: 'spiral', t: 0.75 });
+    assert.equal(blendParts('circle'), null);
+});
+
+test('motion words set spin / drift, but not inside quotes', () => {
+    assert.ok(motionFor('spinning spiral').spin > 0);
+    assert.ok(motionFor('travelling wave').drift > 0);
+    assert.deepEqual(motionFor('"spinning"'), { spin: 0, drift: 0 });
+    assert.deepEqual(motionFor('image:moving car.png'), { spin: 0, drift: 0 });
+});
+
+// ── fitToAspect / snapToGrid ──────────────────────────────────────────────────
+
+test('fitToAspect squeezes the longer axis only', () => {
+    assert.deepEqual([...fitToAspect(new Float32Array([1, 1, -0.5, 0.5]), 2)],   [0.5, 1, -0.25, 0.5]);
+    assert.deepEqual([...fitToAspect(new Float32Array([1, 1, -0.5, 0.5]), 0.5)], [1, 0.5, -0.5, 0.25]);
+    assert.deepEqual([...fitToAspect(new Float32Array([1, 1]), 1)],               [1, 1]);
+});
+
+test('fitToAspect keeps a circle round on wide and tall windows', () => {
+    for (const [w, h] of [[1920, 1080], [1080, 1920], [3440, 1440]]) {
+        // unit points on both axes, then measured in pixels (NDC spans the canvas)
+        const p = fitToAspect(new Float32Array([1, 0, 0, 1, -1, 0, 0, -1]), w / h);
+        const px = [p[0] * w / 2, p[3] * h / 2, -p[4] * w / 2, -p[7] * h / 2];
+        for (const r of px) assert.ok(Math.abs(r - Math.min(w, h) / 2) < 1e-3, `${w}×${h}: ${px}`);
+    }
+});
+
+test('snapToGrid rounds to the lattice from the bottom-left corner', () => {
+    // step 0.25 → cells 0.5 NDC wide: lattice at -1, -0.5, 0, 0.5, 1
+    const p = snapToGrid(new Float32Array([-0.8, 0.3, 0.2, -0.26, 1, -1]), 0.25);
+    assert.deepEqual([...p], [-1, 0.5, 0, -0.5, 1, -1]);
+});
+
+test('snapToGrid keeps points already on the lattice', () => {
+    const p = new Float32Array([-0.5, 0.5]);
+    assert.deepEqual([...snapToGrid(p, 0.25)], [-0.5, 0.5]);
+});
+
+// ── dotGrid ───────────────────────────────────────────────────────────────────
+
+/** Runs of lit rows / columns in a density grid: the number of dot clusters per axis. */
+function clusters(grid) {
+    const G = GRID_SIZE;
+    const rowSum = new Float32Array(G), colSum = new Float32Array(G);
+    for (let r = 0; r < G; r++) {
+        for (let c = 0; c < G; c++) {
+            rowSum[r] += grid[r * G + c];
+            colSum[c] += grid[r * G + c];
+        }
+    }
+    const runs = sums => {
+        const cut = Math.max(...sums) * 0.1;
+        let n = 0;
+        for (let i = 0; i < G; i++) if (sums[i] > cut && !(i > 0 && sums[i - 1] > cut)) n++;
+        return n;
+    };
+    return { rows: runs(rowSum), cols: runs(colSum) };
+}
+
+test('dotGrid(2, 8) has two rows and eight columns of dots', () => {
+    assert.deepEqual(clusters(dotGrid(2, 8)), { rows: 2, cols: 8 });
+    assert.deepEqual(clusters(dotGrid(8, 2)), { rows: 8, cols: 2 });
+});
+
+test('the grid 2x8 prompt builds the same 2 × 8 grid', () => {
+    assert.deepEqual(getShape(resolveShape('grid 2x8')), dotGrid(2, 8));
+});
@@ -0,0 +1,20 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { parseScript } from '../src/ui/script.js';
+
+test('one prompt per line, trimmed', () => {
+    assert.deepEqual(parseScript('circle\n  spiral galaxy  \nheart'), ['circle', 'spiral galaxy', 'heart']);
+});
+
+test('blank lines and # comments are skipped', () => {
+    assert.deepEqual(parseScript('# intro\n\ncircle\n   \n  # aside\nstar\n'), ['circle', 'star']);
+});
+
+test('CRLF line endings', () => {
+    assert.deepEqual(parseScript('circle\r\nstar\r\n'), ['circle', 'star']);
+});
+
+test('empty script has no prompts', () => {
+    assert.deepEqual(parseScript(''), []);
+});
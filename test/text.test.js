@@ -0,0 +1,33 @@
+import { test } from 'node:test';
+import assert   from 'node:assert/strict';
+
+import { text }                  from '../src/shapes/text.js';
+import { getShape, resolveShape } from '../src/shapes/registry.js';
+
+const lit = grid => grid.reduce((n, v) => n + (v > 0.05), 0);
+
+test('spells supported glyphs', () => {
+    const g = text('hi');
+    assert.ok(lit(g) > 50);
+    assert.ok(g.every(v => Number.isFinite(v) && v >= 0 && v <= 1));
+});
+
+test('empty and whitespace-only input throws', () => {
+    assert.throws(() => text(''),        /nothing to spell/);
+    assert.throws(() => text('  \t\n '), /nothing to spell/);
+    assert.throws(() => getShape(resolveShape('"   "')), /nothing to spell/);
+});
+
+test('input made only of unknown glyphs throws', () => {
+    assert.throws(() => text('@@@'), /nothing to spell in "@@@"/);
+    assert.throws(() => text('🙂🙂'), /nothing to spell/);
+});
+
+test('unknown glyphs next to known ones render as gaps', () => {
+    assert.ok(lit(text('a@b')) > 0);
+});
+
+test('input is truncated at MAX_CHARS (64)', () => {
+    assert.deepEqual(text('A'.repeat(80)), text('A'.repeat(64)));
+    assert.notDeepEqual(text('A'.repeat(64)), text('A'.repeat(63)));
+});
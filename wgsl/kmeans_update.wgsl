@@ -1,9 +1,10 @@
 /**
  * kmeans_update.wgsl — Accumulate each point's position into its centroid's sum.
  *
- * Uses fixed-point arithmetic (scale=16384) so positions ∈ [-1,1] map to i32
- * values ∈ [-16384, 16384].  Max accumulation per centroid: 100k × 16384 = 1.64B,
- * safely within i32 range (2.1B).
+ * Uses fixed-point arithmetic (SCALE = 1024) so positions ∈ [-1,1] map to i32
+ * values ∈ [-1024, 1024].  In the worst case every atom lands in one centroid
+ * at |pos| = 1, so a sum reaches N × 1024; i32 holds 2^31 / 1024 ≈ 2.097M such
+ * atoms.  constants.js therefore caps N at 2 000 000 (sum ≤ 2.048B < 2.147B).
  *
  * Dispatched as ceil(N/256) workgroups of size 256.
  */
@@ -4,17 +4,41 @@
  * Two modes, selected by SimParams.has_targets:
  *
  *   Morph mode (has_targets > 0):
- *     Smoothstep interpolation from source_buf → target_buf over morph_t ∈ [0,1].
+ *     Eased interpolation from source_buf → target_buf over morph_t ∈ [0,1],
+ *     using the curve selected by SimParams.easing (default smoothstep).
  *     Velocity dims to zero on arrival so atoms settle cleanly.
+ *     EASE_SPRING instead integrates a damped spring toward target_buf and
+ *     ignores morph_t — atoms overshoot and settle on their own schedule.
+ *     Once an interpolated morph completes (morph_t = 1) atoms hold their
+ *     target with the same spring, so pointer disturbances settle back.
+ *     SimParams.stagger delays each atom's start by up to stagger.x of the
+ *     morph, ordered by atom index or by its target's distance from a focal
+ *     point (a ripple); atoms hold still until their delay has passed.
+ *     While a burst is live (burst.z = age ≥ 0) atoms fly ballistically from
+ *     their targets, outward from the burst origin, under GRAVITY; when it
+ *     ends the hold spring pulls them back into the shape.
+ *     SimParams.motion animates the targets themselves: they spin about the
+ *     centre (aspect-corrected) and/or drift right, wrapping at the edge, so
+ *     a held shape keeps moving.  Atoms that wrap are carried across with
+ *     their target instead of springing back over the whole screen.
  *
  *   Wander mode (has_targets == 0):
  *     Two-frequency sinusoidal force field with per-atom phase offsets.
  *     Soft quadratic wall repulsion keeps atoms inside ±BOUND.
  *
+ *   The pointer force (params.pointer) is added to velocity before the spring
+ *   or wander forces, wherever atoms integrate velocity.
+ *
+ *   SimParams.boundary selects what happens at the screen edge (±1) wherever
+ *   atoms integrate velocity: none (spring is free, wander clamps), clamp,
+ *   bounce (reflect position and velocity) or wrap (teleport across).
+ *
  * Bindings (group 0):
  *   0  src_atoms  — storage read       (ping-pong source)
  *   1  dst_atoms  — storage read_write (ping-pong destination)
- *   2  params     — uniform            (dt, time, has_targets, morph_t)
+ *   2  params     — uniform            (dt, time, has_targets, morph_t, easing,
+ *                                        spring_k, damping, boundary, pointer,
+ *                                        stagger, burst, motion)
  *   3  target_buf — storage read       (OT-assigned 2D target positions)
  *   4  source_buf — storage read       (OT source positions at transition start)
  */
@@ -29,6 +53,14 @@ struct SimParams {
     time        : f32,
     has_targets : f32,
     morph_t     : f32,
+    easing      : f32,   // EASE_* selector
+    spring_k    : f32,   // spring stiffness per 60 fps frame   [0, 0.5]
+    damping     : f32,   // velocity retained per 60 fps frame  [0, 1]
+    boundary    : f32,   // EDGE_* selector
+    pointer     : vec4<f32>,   // (x, y, radius, strength) — strength 0 = off
+    stagger     : vec4<f32>,   // (amount ∈ [0, 0.9], order, focus.x, focus.y)
+    burst       : vec4<f32>,   // (origin.x, origin.y, age s — < 0 = off, speed NDC/s)
+    motion      : vec4<f32>,   // (spin rad/s, drift NDC/s, start time s, aspect w/h)
 }
 
 @group(0) @binding(0) var<storage, read>       src_atoms  : array<Atom>;
@@ -41,6 +73,128 @@ const MAX_VEL : f32 = 0.55;
 const N       : u32 = %%N%%;
 const BOUND   : f32 = 0.92;
 
+// Easing selectors — must match EASINGS order in main.js
+const EASE_LINEAR      : f32 = 0.0;
+const EASE_IN_OUT      : f32 = 1.0;
+const EASE_OUT_CUBIC   : f32 = 2.0;
+const EASE_OUT_BACK    : f32 = 3.0;
+const EASE_OUT_ELASTIC : f32 = 4.0;
+const EASE_IN_OUT_QUAD : f32 = 5.0;
+const EASE_IN_OUT_CUBIC: f32 = 6.0;
+const EASE_SPRING      : f32 = 7.0;   // keep last: every mode above it springs
+
+// Burst: downward pull while atoms are airborne (NDC/s²)
+const GRAVITY : vec2<f32> = vec2<f32>(0.0, -1.6);
+
+// Boundary selectors — must match BOUNDARIES order in main.js
+const EDGE_NONE   : f32 = 0.0;
+const EDGE_CLAMP  : f32 = 1.0;
+const EDGE_BOUNCE : f32 = 2.0;
+const EDGE_WRAP   : f32 = 3.0;
+
+// Spring mode integrates in per-frame units at a 60 fps baseline, scaled by
+// how many such frames dt spans (capped so a hitch can't blow the spring up)
+const FRAME      : f32 = 1.0 / 60.0;
+const MAX_FRAMES : f32 = 2.0;
+
+// Radial pointer force in NDC/s²: positive pulls toward the pointer, negative
+// pushes away.  Linear falloff to zero at the radius; zero outside it.
+fn pointer_force(p : vec2<f32>) -> vec2<f32> {
+    let d = params.pointer.xy - p;
+    let r = length(d);
+    if params.pointer.w == 0.0 || r >= params.pointer.z || r < 1e-5 {
+        return vec2<f32>(0.0);
+    }
+    return d / r * params.pointer.w * (1.0 - r / params.pointer.z);
+}
+
+// Keep an atom inside the ±1 screen square according to params.boundary.
+// EDGE_NONE returns the atom unchanged.
+fn apply_boundary(atom : Atom) -> Atom {
+    var a = atom;
+    let mode = params.boundary;
+    if mode < EDGE_CLAMP - 0.5 { return a; }
+
+    let out = abs(a.pos) > vec2<f32>(1.0);
+    if mode < EDGE_BOUNCE - 0.5 {
+        a.pos = clamp(a.pos, vec2<f32>(-1.0), vec2<f32>(1.0));
+        a.vel = select(a.vel, vec2<f32>(0.0), out);
+    } else if mode < EDGE_WRAP - 0.5 {
+        a.pos = select(a.pos, sign(a.pos) * 2.0 - a.pos, out);
+        a.vel = select(a.vel, -a.vel, out);
+    } else {
+        a.pos = select(a.pos, a.pos - sign(a.pos) * 2.0, out);
+    }
+    return a;
+}
+
+// Per-atom pseudo-random value in [0, 1) (PCG hash of the index).
+fn hash(n : u32) -> f32 {
+    var x = n * 747796405u + 2891336453u;
+    x = ((x >> ((x >> 28u) + 4u)) ^ x) * 277803737u;
+    x = (x >> 22u) ^ x;
+    return f32(x) / 4294967296.0;
+}
+
+// Ballistic flight from the target, launched away from the burst origin with
+// a little angular spread and a per-atom speed in [0.4, 1] × burst.w.
+fn burst_flight(idx : u32, tp : vec2<f32>) -> Atom {
+    let away   = tp - params.burst.xy;
+    let base   = select(hash(idx * 3u + 1u) * 6.2831853, atan2(away.y, away.x), length(away) > 1e-4);
+    let ang    = base + (hash(idx * 3u + 2u) - 0.5) * 0.6;
+    let v0     = vec2<f32>(cos(ang), sin(ang)) * params.burst.w * (0.4 + 0.6 * hash(idx));
+    let age    = params.burst.z;
+    var a : Atom;
+    a.pos = tp + v0 * age + 0.5 * GRAVITY * age * age;
+    a.vel = v0 + GRAVITY * age;
+    return a;
+}
+
+// Fraction of the morph this atom waits before moving, in [0, stagger.x].
+// Order 0 staggers by atom index; order 1 by target distance from the focus.
+fn stagger_delay(idx : u32, tp : vec2<f32>) -> f32 {
+    let f = select(f32(idx) / f32(N),
+                   clamp(length(tp - params.stagger.zw) / 1.5, 0.0, 1.0),
+                   params.stagger.y > 0.5);
+    return params.stagger.x * f;
+}
+
+// Every curve maps 0 → 0 and 1 → 1.  Back and elastic overshoot the target
+// on the way in, so atoms briefly pass their spot before settling.
+fn ease(t : f32, mode : f32) -> f32 {
+    if mode < EASE_IN_OUT - 0.5    { return t; }                        // linear
+    if mode < EASE_OUT_CUBIC - 0.5 { return t * t * (3.0 - 2.0 * t); }  // smoothstep
+    let u = t - 1.0;
+    if mode < EASE_OUT_BACK - 0.5  { return 1.0 + u * u * u; }          // ease-out cubic
+    if mode < EASE_OUT_ELASTIC - 0.5 {                                  // ease-out back
+        return 1.0 + 2.70158 * u * u * u + 1.70158 * u * u;
+    }
+    if mode < EASE_IN_OUT_QUAD - 0.5 {                                  // ease-out elastic
+        if t <= 0.0 || t >= 1.0 { return t; }
+        return pow(2.0, -10.0 * t) * sin((10.0 * t - 0.75) * 2.0943951) + 1.0;
+    }
+    let v = 1.0 - t;
+    if mode < EASE_IN_OUT_CUBIC - 0.5 {                                 // ease-in-out quad
+        return select(1.0 - 2.0 * v * v, 2.0 * t * t, t < 0.5);
+    }
+    return select(1.0 - 4.0 * v * v * v, 4.0 * t * t * t, t < 0.5);     // ease-in-out cubic
+}
+
+// Target after params.motion: rotated by spin·age in aspect-corrected space,
+// then shifted right by drift·age and wrapped back into [-1, 1].
+fn animate(tp : vec2<f32>) -> vec2<f32> {
+    let age = params.time - params.motion.z;
+    let sq  = vec2<f32>(params.motion.w, 1.0);
+    let ang = params.motion.x * age;
+    let q   = tp * sq;
+    var p   = vec2<f32>(cos(ang) * q.x - sin(ang) * q.y,
+                        sin(ang) * q.x + cos(ang) * q.y) / sq;
+    if params.motion.y != 0.0 {
+        p.x = fract((p.x + 1.0 + params.motion.y * age) * 0.5) * 2.0 - 1.0;
+    }
+    return p;
+}
+
 @compute @workgroup_size(256)
 fn cs_main(@builtin(global_invocation_id) gid : vec3<u32>) {
     let idx = gid.x;
@@ -50,11 +204,43 @@ fn cs_main(@builtin(global_invocation_id) gid : vec3<u32>) {
 
     // ── Morph mode ──────────────────────────────────────────────────────────
     if params.has_targets > 0.5 {
-        let t  = clamp(params.morph_t, 0.0, 1.0);
-        let te = t * t * (3.0 - 2.0 * t);   // smoothstep — ease in/out
+        let tp    = animate(target_buf[idx]);
+
+        if params.burst.z >= 0.0 {
+            dst_atoms[idx] = apply_boundary(burst_flight(idx, tp));
+            return;
+        }
+
+        let delay = stagger_delay(idx, tp);
+
+        // Not this atom's turn yet — hold still
+        if params.morph_t < delay {
+            a.vel = vec2<f32>(0.0);
+            dst_atoms[idx] = a;
+            return;
+        }
+
+        // Spring: integrate in per-frame units, store velocity back in NDC/s.
+        // Also holds settled atoms once an interpolated morph has finished.
+        if params.easing > EASE_SPRING - 0.5 || params.morph_t >= 1.0 {
+            // A drifting target that wrapped carries its atom across with it
+            if params.motion.y != 0.0 {
+                a.pos.x += 2.0 * round((tp.x - a.pos.x) * 0.5);
+            }
+            let s  = clamp(params.dt / FRAME, 0.0, MAX_FRAMES);
+            let pv = a.vel + pointer_force(a.pos) * params.dt;
+            let v  = (pv * FRAME + (tp - a.pos) * params.spring_k * s)
+                   * pow(max(params.damping, 1e-6), s);
+            a.pos += v * s;
+            a.vel  = v / FRAME;
+            dst_atoms[idx] = apply_boundary(a);
+            return;
+        }
+
+        let t  = clamp((params.morph_t - delay) / (1.0 - params.stagger.x), 0.0, 1.0);
+        let te = ease(t, params.easing);
 
         let sp = source_buf[idx];
-        let tp = target_buf[idx];
 
         a.pos = mix(sp, tp, te);
         a.vel = (tp - sp) * (1.0 - te);     // velocity dims to zero on arrival
@@ -79,13 +265,17 @@ fn cs_main(@builtin(global_invocation_id) gid : vec3<u32>) {
     if a.pos.y < -BOUND { wall.y =  5.5 * (-BOUND - a.pos.y); }
     if a.pos.y >  BOUND { wall.y = -5.5 * ( a.pos.y - BOUND); }
 
-    // Velocity update: force + wall, then damp
-    a.vel = (a.vel + (vec2<f32>(fx, fy) + wall) * params.dt) * 0.992;
+    // Velocity update: pointer + force + wall, then damp
+    let f = pointer_force(a.pos) + vec2<f32>(fx, fy) + wall;
+    a.vel = (a.vel + f * params.dt) * 0.992;
 
     // Speed clamp
     let spd = length(a.vel);
     if spd > MAX_VEL { a.vel *= MAX_VEL / spd; }
 
-    a.pos = clamp(a.pos + a.vel * params.dt, vec2<f32>(-1.0), vec2<f32>(1.0));
-    dst_atoms[idx] = a;
+    a.pos += a.vel * params.dt;
+    if params.boundary < EDGE_CLAMP - 0.5 {
+        a.pos = clamp(a.pos, vec2<f32>(-1.0), vec2<f32>(1.0));
+    }
+    dst_atoms[idx] = apply_boundary(a);
 }
@@ -15,16 +15,43 @@
  *   Applies a 3×3 Gaussian filter (σ=1) for smooth anti-aliased appearance.
  *   Maps trail → brightness via log tone curve.
  *   Outputs green-phosphor colour ramp shifted white-hot for fast atoms.
+ *   A palette can replace the phosphor hue with a dim → bright ramp, or a
+ *   rainbow whose hue runs around the current shape's centre.
+ *   An optional radial falloff dims the glow with distance from the shape's
+ *   centre (linear, inverse-square or Gaussian), so the core stands out.
+ *   An optional gradient swaps the phosphor hue for a start → end colour
+ *   laid across the current shape's bounds (horizontal, vertical or radial).
+ *   Empty space shows a vertical bottom → top background gradient.
+ *   A global alpha fades the whole glow in or out over the background.
+ *   A 2D camera (offset + zoom) picks which part of the world is sampled; the
+ *   background stays fixed to the screen.
  *
  * Bindings (group 0):
  *   0  trail_buf   — storage read  (f32, persistent decayed glow)
  *   1  vel_buf     — storage read  (u32, current frame speed accumulator)
  *   2  density_buf — storage read  (u32, current frame atom counts)
+ *   3  rp          — uniform       (RenderParams)
  */
 
+struct RenderParams {
+    grad_start : vec4<f32>,   // rgb at gradient t = 0
+    grad_end   : vec4<f32>,   // rgb at gradient t = 1
+    grad_box   : vec4<f32>,   // NDC bounds of the current shape: (min.xy, max.xy)
+    grad_axis  : f32,         // 0 off, 1 horizontal, 2 vertical, 3 radial
+    alpha      : f32,         // global glow opacity — 0 hides the atoms, 1 = normal
+    palette    : f32,         // 0 phosphor, 1 ramp (pal_dim → pal_bright), 2 rainbow
+    falloff    : f32,         // 0 off, 1 linear, 2 inverse-square, 3 Gaussian
+    bg_top     : vec4<f32>,   // background rgb at the top edge
+    bg_bottom  : vec4<f32>,   // background rgb at the bottom edge
+    camera     : vec4<f32>,   // (offset.xy, zoom, _) — world = screen / zoom + offset
+    pal_dim    : vec4<f32>,   // ramp palette rgb for faint glow
+    pal_bright : vec4<f32>,   // ramp palette rgb for dense glow
+}
+
 @group(0) @binding(0) var<storage, read> trail_buf   : array<f32>;
 @group(0) @binding(1) var<storage, read> vel_buf     : array<u32>;
 @group(0) @binding(2) var<storage, read> density_buf : array<u32>;
+@group(0) @binding(3) var<uniform>       rp          : RenderParams;
 
 const DENSITY_W : u32 = %%DENSITY_W%%;
 const DENSITY_H : u32 = %%DENSITY_H%%;
@@ -81,12 +108,43 @@ fn gw(dx : i32, dy : i32) -> f32 {
     return select(select(0.0751, 0.1238, r2 == 1), 0.2042, r2 == 0);
 }
 
+// Position of an NDC point along the gradient, 0 at the start colour.
+fn gradient_t(ndc : vec2<f32>) -> f32 {
+    let lo   = rp.grad_box.xy;
+    let hi   = rp.grad_box.zw;
+    let span = max(hi - lo, vec2<f32>(1e-4));
+    if rp.grad_axis < 1.5 { return clamp((ndc.x - lo.x) / span.x, 0.0, 1.0); }
+    if rp.grad_axis < 2.5 { return clamp((ndc.y - lo.y) / span.y, 0.0, 1.0); }
+    let c = (lo + hi) * 0.5;
+    return clamp(length(ndc - c) / (0.5 * length(span)), 0.0, 1.0);
+}
+
+// Glow weight at an NDC point: 1 at the shape's centre, fading to the rim.
+fn radial_falloff(ndc : vec2<f32>) -> f32 {
+    if rp.falloff < 0.5 { return 1.0; }
+    let lo = rp.grad_box.xy;
+    let hi = rp.grad_box.zw;
+    let d  = length(ndc - (lo + hi) * 0.5) / max(0.5 * length(hi - lo), 1e-4);
+    if rp.falloff < 1.5 { return clamp(1.0 - d, 0.0, 1.0); }
+    if rp.falloff < 2.5 { return 1.0 / (1.0 + 9.0 * d * d); }
+    return exp(-3.0 * d * d);
+}
+
+// Fully saturated colour for hue h in [0, 1).
+fn hue_rgb(h : f32) -> vec3<f32> {
+    let k = abs(fract(h + vec3<f32>(0.0, 2.0 / 3.0, 1.0 / 3.0)) * 6.0 - 3.0) - 1.0;
+    return clamp(k, vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
 // ── Fragment ───────────────────────────────────────────────────────────────
 
 @fragment
 fn fs_main(in : VSOut) -> @location(0) vec4<f32> {
-    let ix = i32(in.uv.x * f32(DENSITY_W));
-    let iy = i32(in.uv.y * f32(DENSITY_H));
+    // Screen → world through the camera
+    let world = (in.uv * 2.0 - 1.0) / rp.camera.z + rp.camera.xy;
+    let wuv   = (world + 1.0) * 0.5;
+    let ix = i32(wuv.x * f32(DENSITY_W));
+    let iy = i32(wuv.y * f32(DENSITY_H));
 
     // 3×3 Gaussian filter — weights sum to 1, no post-divide needed
     var t_sum = 0.0;
@@ -119,12 +177,31 @@ fn fs_main(in : VSOut) -> @location(0) vec4<f32> {
     let r_base = n2 * 0.15 + n3 * 0.12;             // red rises late
     let g_base = norm * 0.20 + n2 * 0.50 + n3 * 0.30; // green always leads
     let b_base = n3 * 0.10;                           // cool blue only at peak
+    var base   = vec3<f32>(r_base, g_base, b_base);
+
+    // Palette: keep the phosphor brightness curve, swap the hue
+    if rp.palette > 1.5 {
+        let d = world - (rp.grad_box.xy + rp.grad_box.zw) * 0.5;
+        base = hue_rgb(atan2(d.y, d.x) / 6.2831853 + 0.5) * g_base;
+    } else if rp.palette > 0.5 {
+        base = mix(rp.pal_dim.rgb, rp.pal_bright.rgb, norm) * g_base;
+    }
+
+    // Gradient: same idea, laid across the shape — overrides the palette
+    if rp.grad_axis > 0.5 {
+        let tint = mix(rp.grad_start.rgb, rp.grad_end.rgb, gradient_t(world));
+        base = tint * g_base;
+    }
 
     // White-hot shift at high speed
     let blend = speed * 0.85;
-    let r = mix(r_base, norm * 0.90, blend);
-    let g = mix(g_base, norm,        blend);
-    let b = mix(b_base, norm * 0.95, blend);
-
-    return vec4<f32>(r, g, b, 1.0);
+    let r = mix(base.r, norm * 0.90, blend);
+    let g = mix(base.g, norm,        blend);
+    let b = mix(base.b, norm * 0.95, blend);
+
+    // Background shows through where the glow is faint (or faded out)
+    let bg   = mix(rp.bg_bottom.rgb, rp.bg_top.rgb, in.uv.y);
+    let a    = rp.alpha * radial_falloff(world);
+    let glow = norm * a;
+    return vec4<f32>(vec3<f32>(r, g, b) * a + bg * (1.0 - glow), 1.0);
 }
@@ -57,6 +57,10 @@ fn cs_splat(@builtin(global_invocation_id) gid : vec3<u32>) {
 
     let p = atoms[idx].pos;
 
+    // Off-screen atoms (burst, spring overshoot) are simply not drawn rather
+    // than piling up on the edge pixels
+    if any(abs(p) > vec2<f32>(1.0)) { return; }
+
     // NDC [-1,+1] → continuous pixel-space coords in [0, W/H)
     let fx_raw = (p.x * 0.5 + 0.5) * f32(DENSITY_W);
     let fy_raw = (p.y * 0.5 + 0.5) * f32(DENSITY_H);